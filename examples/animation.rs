@@ -30,7 +30,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .map_err(|e| e.to_string())?;
 
     #[cfg(not(feature = "unsafe_textures"))]
-    texture.set_scale_mode(sdl3::render::ScaleMode::Nearest);
+    texture.set_scale_mode(sdl3::render::ScaleMode::Nearest)?;
 
     let frames_per_anim = 4;
     let sprite_tile_size = (32., 32.);
@@ -0,0 +1,52 @@
+use sdl3::audio::{AudioFormat, AudioPullCallback, AudioSpec};
+use std::f32::consts::TAU;
+use std::time::Duration;
+
+struct SineWave {
+    phase_inc: f32,
+    phase: f32,
+    volume: f32,
+}
+
+impl AudioPullCallback<f32> for SineWave {
+    fn callback(&mut self, out: &mut [f32]) {
+        for x in out.iter_mut() {
+            *x = (self.phase * TAU).sin() * self.volume;
+            self.phase = (self.phase + self.phase_inc) % 1.0;
+        }
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let sdl_context = sdl3::init()?;
+    let audio_subsystem = sdl_context.audio()?;
+
+    let desired_spec = AudioSpec {
+        freq: Some(48000),
+        channels: Some(1), // mono
+        format: Some(AudioFormat::f32_sys()),
+    };
+
+    let mut device = audio_subsystem.open_playback_pull_stream(
+        &desired_spec,
+        SineWave {
+            phase_inc: 440.0 / desired_spec.freq.unwrap() as f32,
+            phase: 0.0,
+            volume: 0.25,
+        },
+    )?;
+
+    // Start playback
+    device.resume()?;
+    std::thread::sleep(Duration::from_millis(1_000));
+
+    // Shift up an octave; locking keeps the callback from running while we modify it
+    if let Some(mut sine) = device.lock() {
+        sine.phase_inc *= 2.0;
+    }
+    std::thread::sleep(Duration::from_millis(1_000));
+
+    // Device and callback are freed when dropped
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use sdl3::pixels::Color;
+use std::time::Duration;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // This is required for certain controllers to work on Windows without the
+    // video subsystem enabled:
+    sdl3::hint::set("SDL_JOYSTICK_THREAD", "1");
+
+    let sdl_context = sdl3::init()?;
+    let gamepad_subsystem = sdl_context.gamepad()?;
+
+    let mut controller = gamepad_subsystem
+        .gamepads()
+        .map_err(|e| format!("can't enumerate gamepads: {e}"))?
+        .into_iter()
+        .find_map(|id| gamepad_subsystem.open(id).ok())
+        .ok_or("Couldn't open any gamepad")?;
+
+    println!(
+        "Opened \"{}\", cycling the LED, press any button to quit",
+        controller.name().unwrap_or_else(|| "(unnamed)".to_owned())
+    );
+
+    let colors = [Color::RED, Color::GREEN, Color::BLUE];
+    let mut event_pump = sdl_context.event_pump()?;
+
+    'running: for color in colors.iter().cycle() {
+        if let Err(e) = controller.set_led(*color) {
+            println!("Error setting LED: {e}");
+            break;
+        }
+
+        for event in event_pump.poll_iter() {
+            use sdl3::event::Event;
+
+            match event {
+                Event::ControllerButtonDown { .. } | Event::Quit { .. } => break 'running,
+                _ => (),
+            }
+        }
+
+        std::thread::sleep(Duration::from_millis(500));
+    }
+
+    Ok(())
+}
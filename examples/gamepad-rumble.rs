@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // This is required for certain controllers to work on Windows without the
+    // video subsystem enabled:
+    sdl3::hint::set("SDL_JOYSTICK_THREAD", "1");
+
+    let sdl_context = sdl3::init()?;
+    let gamepad_subsystem = sdl_context.gamepad()?;
+
+    let mut controller = gamepad_subsystem
+        .gamepads()
+        .map_err(|e| format!("can't enumerate gamepads: {e}"))?
+        .into_iter()
+        .find_map(|id| gamepad_subsystem.open(id).ok())
+        .ok_or("Couldn't open any gamepad")?;
+
+    println!(
+        "Opened \"{}\", press any button to rumble, Start to quit",
+        controller.name().unwrap_or_else(|| "(unnamed)".to_owned())
+    );
+
+    for event in sdl_context.event_pump()?.wait_iter() {
+        use sdl3::event::Event;
+        use sdl3::gamepad::Button;
+
+        match event {
+            Event::ControllerButtonDown {
+                button: Button::Start,
+                ..
+            }
+            | Event::Quit { .. } => break,
+            Event::ControllerButtonDown { button, .. } => {
+                let duration = Duration::from_millis(200);
+                match controller.rumble(0xFFFF, 0xFFFF, duration) {
+                    Ok(()) => println!("{button:?} pressed, rumbling for {duration:?}"),
+                    Err(e) => println!("Error rumbling: {e}"),
+                }
+                // Not every gamepad has trigger motors; ignore the error.
+                let _ = controller.rumble_triggers(0xFFFF, 0xFFFF, duration);
+            }
+            _ => (),
+        }
+    }
+
+    Ok(())
+}
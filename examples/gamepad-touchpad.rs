@@ -0,0 +1,57 @@
+use std::time::Duration;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // This is required for certain controllers to work on Windows without the
+    // video subsystem enabled:
+    sdl3::hint::set("SDL_JOYSTICK_THREAD", "1");
+
+    let sdl_context = sdl3::init()?;
+    let gamepad_subsystem = sdl_context.gamepad()?;
+
+    let controller = gamepad_subsystem
+        .gamepads()
+        .map_err(|e| format!("can't enumerate gamepads: {e}"))?
+        .into_iter()
+        .filter_map(|id| gamepad_subsystem.open(id).ok())
+        .find(|gamepad| gamepad.touchpads_count() > 0)
+        .ok_or("Couldn't open any gamepad with a touchpad")?;
+
+    println!(
+        "Opened \"{}\", touch the touchpad, press any button to quit",
+        controller.name().unwrap_or_else(|| "(unnamed)".to_owned())
+    );
+
+    let mut event_pump = sdl_context.event_pump()?;
+    let mut was_down = false;
+
+    'running: loop {
+        for event in event_pump.poll_iter() {
+            use sdl3::event::Event;
+
+            match event {
+                Event::ControllerButtonDown { .. } | Event::Quit { .. } => break 'running,
+                _ => (),
+            }
+        }
+
+        // Poll the primary finger on the first touchpad, once per frame.
+        match controller.touchpad_finger(0, 0) {
+            Some(finger) if finger.down => {
+                println!(
+                    "finger at ({:.3}, {:.3}), pressure {:.3}",
+                    finger.x, finger.y, finger.pressure
+                );
+                was_down = true;
+            }
+            Some(_) if was_down => {
+                println!("finger lifted");
+                was_down = false;
+            }
+            _ => (),
+        }
+
+        std::thread::sleep(Duration::from_millis(16));
+    }
+
+    Ok(())
+}
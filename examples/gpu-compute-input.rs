@@ -0,0 +1,106 @@
+use sdl3::gpu::*;
+use std::mem::size_of;
+
+const VALUE_COUNT: u32 = 256;
+const THREADS_PER_GROUP: u32 = 64;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let _sdl = sdl3::init()?;
+    let device = Device::new(ShaderFormat::SPIRV, true)?;
+
+    let inputs: Vec<u32> = (0..VALUE_COUNT).collect();
+    let buffer_size = VALUE_COUNT * size_of::<u32>() as u32;
+
+    // The shader only reads from this buffer, so it is bound on the pass
+    // rather than handed to `begin_compute_pass`.
+    let input = device
+        .create_buffer()
+        .with_size(buffer_size)
+        .with_usage(BufferUsageFlags::COMPUTE_STORAGE_READ)
+        .build()?;
+    let output = device
+        .create_buffer()
+        .with_size(buffer_size)
+        .with_usage(BufferUsageFlags::COMPUTE_STORAGE_WRITE)
+        .build()?;
+
+    let pipeline = device
+        .create_compute_pipeline()
+        .with_code(
+            ShaderFormat::SPIRV,
+            include_bytes!("shaders/compute-double.comp.spv"),
+        )
+        .with_entrypoint(c"main")
+        .with_readonly_storage_buffers(1)
+        .with_readwrite_storage_buffers(1)
+        .with_thread_count(THREADS_PER_GROUP, 1, 1)
+        .build()?;
+
+    let upload = device
+        .create_transfer_buffer()
+        .with_size(buffer_size)
+        .with_usage(TransferBufferUsage::UPLOAD)
+        .build()?;
+    let download = device
+        .create_transfer_buffer()
+        .with_size(buffer_size)
+        .with_usage(TransferBufferUsage::DOWNLOAD)
+        .build()?;
+
+    let mut map = upload.map::<u32>(&device, true);
+    map.mem_mut().copy_from_slice(&inputs);
+    map.unmap();
+
+    let command_buffer = device.acquire_command_buffer()?;
+
+    // === Upload the input ===
+    let copy_pass = device.begin_copy_pass(&command_buffer)?;
+    copy_pass.upload_to_gpu_buffer(
+        TransferBufferLocation::new()
+            .with_transfer_buffer(&upload)
+            .with_offset(0),
+        BufferRegion::new()
+            .with_buffer(&input)
+            .with_offset(0)
+            .with_size(buffer_size),
+        false,
+    );
+    device.end_copy_pass(copy_pass);
+
+    // === Run compute shader ===
+    let binding = StorageBufferReadWriteBinding::new()
+        .with_buffer(&output)
+        .with_cycle(false);
+    let compute_pass = device.begin_compute_pass(&command_buffer, &[], &[binding])?;
+    compute_pass.bind_compute_pipeline(&pipeline);
+    compute_pass.bind_storage_buffers(0, &[&input]);
+    compute_pass.dispatch(VALUE_COUNT.div_ceil(THREADS_PER_GROUP), 1, 1);
+    device.end_compute_pass(compute_pass);
+
+    // === Read back ===
+    let copy_pass = device.begin_copy_pass(&command_buffer)?;
+    copy_pass.download_from_gpu_buffer(
+        BufferRegion::new()
+            .with_buffer(&output)
+            .with_offset(0)
+            .with_size(buffer_size),
+        TransferBufferLocation::new()
+            .with_transfer_buffer(&download)
+            .with_offset(0),
+    );
+    device.end_copy_pass(copy_pass);
+
+    let fence = command_buffer.submit_and_acquire_fence(&device)?;
+    device.wait_fences(true, &[fence])?;
+
+    let map = download.map::<u32>(&device, false);
+    let outputs = map.mem().to_vec();
+    map.unmap();
+
+    for (input, output) in inputs.iter().zip(&outputs) {
+        assert_eq!(*output, input * 2);
+    }
+    println!("doubled {VALUE_COUNT} values, first: {:?}", &outputs[..8]);
+
+    Ok(())
+}
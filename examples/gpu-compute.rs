@@ -0,0 +1,77 @@
+use sdl3::gpu::*;
+use std::mem::size_of;
+
+const VALUE_COUNT: u32 = 256;
+const THREADS_PER_GROUP: u32 = 64;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let _sdl = sdl3::init()?;
+
+    // No window is needed: the results are read back from GPU memory.
+    let device = Device::new(ShaderFormat::SPIRV, true)?;
+
+    let buffer_size = VALUE_COUNT * size_of::<u32>() as u32;
+    let output = device
+        .create_buffer()
+        .with_size(buffer_size)
+        .with_usage(BufferUsageFlags::COMPUTE_STORAGE_WRITE)
+        .build()?;
+
+    let pipeline = device
+        .create_compute_pipeline()
+        .with_code(
+            ShaderFormat::SPIRV,
+            include_bytes!("shaders/compute-fill.comp.spv"),
+        )
+        .with_entrypoint(c"main")
+        .with_readwrite_storage_buffers(1)
+        .with_thread_count(THREADS_PER_GROUP, 1, 1)
+        .build()?;
+
+    let download = device
+        .create_transfer_buffer()
+        .with_size(buffer_size)
+        .with_usage(TransferBufferUsage::DOWNLOAD)
+        .build()?;
+
+    let command_buffer = device.acquire_command_buffer()?;
+
+    // === Run compute shader ===
+    let binding = StorageBufferReadWriteBinding::new()
+        .with_buffer(&output)
+        .with_cycle(false);
+    let compute_pass = device.begin_compute_pass(&command_buffer, &[], &[binding])?;
+    // The pipeline has to be bound before dispatching, otherwise nothing runs.
+    compute_pass.bind_compute_pipeline(&pipeline);
+    compute_pass.dispatch(VALUE_COUNT.div_ceil(THREADS_PER_GROUP), 1, 1);
+    device.end_compute_pass(compute_pass);
+
+    // === Copy the results into CPU-visible memory ===
+    let copy_pass = device.begin_copy_pass(&command_buffer)?;
+    copy_pass.download_from_gpu_buffer(
+        BufferRegion::new()
+            .with_buffer(&output)
+            .with_offset(0)
+            .with_size(buffer_size),
+        TransferBufferLocation::new()
+            .with_transfer_buffer(&download)
+            .with_offset(0),
+    );
+    device.end_copy_pass(copy_pass);
+
+    let fence = command_buffer.submit_and_acquire_fence(&device)?;
+    device.wait_fences(true, &[fence])?;
+
+    // === Read back ===
+    let map = download.map::<u32>(&device, false);
+    let values = map.mem().to_vec();
+    map.unmap();
+
+    for (i, value) in values.iter().enumerate() {
+        assert_eq!(*value, (i * i) as u32, "unexpected value at index {i}");
+    }
+    println!("first values: {:?}", &values[..8]);
+    println!("all {VALUE_COUNT} values match");
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use sdl3::{
+    event::Event,
+    gpu::{
+        ColorTargetInfo, Device, LoadOp, PresentMode, ShaderFormat, StoreOp, SwapchainComposition,
+    },
+    keyboard::Keycode,
+    pixels::Color,
+};
+use std::time::{Duration, Instant};
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let sdl_context = sdl3::init()?;
+    let video_subsystem = sdl_context.video()?;
+    let window = video_subsystem
+        .window("rust-sdl3 demo: GPU (present mode)", 800, 600)
+        .position_centered()
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let gpu = Device::new(ShaderFormat::SPIRV, true)?.with_window(&window)?;
+
+    let mut present_mode = PresentMode::Vsync;
+    println!("Press space to switch between Vsync and Immediate presentation.");
+
+    let mut event_pump = sdl_context.event_pump()?;
+    let mut frames = 0u32;
+    let mut shade = 0u8;
+    let mut last_report = Instant::now();
+    'running: loop {
+        for event in event_pump.poll_iter() {
+            match event {
+                Event::Quit { .. }
+                | Event::KeyDown {
+                    keycode: Some(Keycode::Escape),
+                    ..
+                } => break 'running,
+                Event::KeyDown {
+                    keycode: Some(Keycode::Space),
+                    repeat: false,
+                    ..
+                } => {
+                    let next = match present_mode {
+                        PresentMode::Vsync => PresentMode::Immediate,
+                        _ => PresentMode::Vsync,
+                    };
+                    if gpu.window_supports_present_mode(&window, next) {
+                        gpu.set_swapchain_parameters(&window, next, SwapchainComposition::Sdr)?;
+                        present_mode = next;
+                        println!("Switched to {present_mode:?}");
+                    } else {
+                        println!("{next:?} is not supported by this driver");
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut command_buffer = gpu.acquire_command_buffer()?;
+        if let Ok(swapchain) = command_buffer.wait_and_acquire_swapchain_texture(&window) {
+            // Cycle the clear color so that tearing is visible without vsync.
+            shade = shade.wrapping_add(1);
+            let color_targets = [ColorTargetInfo::default()
+                .with_texture(&swapchain)
+                .with_load_op(LoadOp::CLEAR)
+                .with_store_op(StoreOp::STORE)
+                .with_clear_color(Color::RGB(shade, 64, 255 - shade))];
+            let render_pass = gpu.begin_render_pass(&command_buffer, &color_targets, None)?;
+            gpu.end_render_pass(render_pass);
+            command_buffer.submit()?;
+            frames += 1;
+        } else {
+            command_buffer.cancel();
+        }
+
+        let elapsed = last_report.elapsed();
+        if elapsed >= Duration::from_secs(1) {
+            println!(
+                "{present_mode:?}: {:.0} fps",
+                frames as f64 / elapsed.as_secs_f64()
+            );
+            frames = 0;
+            last_report = Instant::now();
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use sdl3::haptic::HapticEffect;
+use std::time::Duration;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let sdl_context = sdl3::init()?;
+    let joystick_subsystem = sdl_context.joystick()?;
+    let haptic_subsystem = sdl_context.haptic()?;
+
+    let haptic = joystick_subsystem
+        .joysticks()
+        .map_err(|e| format!("can't enumerate joysticks: {e}"))?
+        .into_iter()
+        .find_map(|id| haptic_subsystem.open_from_joystick_id(id.into()).ok())
+        .ok_or("Couldn't open any haptic device")?;
+
+    let length = Duration::from_millis(500);
+    let effect = HapticEffect::constant(0x6000, length);
+    if !haptic.effect_supported(&effect) {
+        return Err("The device doesn't support constant effects".into());
+    }
+
+    let id = haptic.upload_effect(&effect)?;
+    println!("Uploaded effect {id:?}, playing it for {length:?}");
+    haptic.run_effect(id, 1)?;
+
+    std::thread::sleep(length);
+    haptic.destroy_effect(id);
+
+    Ok(())
+}
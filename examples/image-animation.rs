@@ -0,0 +1,42 @@
+use sdl3::image::Animation;
+use std::env;
+use std::path::Path;
+
+pub fn run(path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let animation = Animation::from_file(path)?;
+    println!(
+        "{}: {}x{}, {} frames",
+        path.display(),
+        animation.width(),
+        animation.height(),
+        animation.frames().len()
+    );
+
+    let mut total = 0;
+    for (i, frame) in animation.frames().iter().enumerate() {
+        let surface = frame.surface();
+        println!(
+            "frame {i}: {}x{} {:?}, shown for {}ms",
+            surface.width(),
+            surface.height(),
+            surface.pixel_format(),
+            frame.delay()
+        );
+        total += frame.delay();
+    }
+    println!("total duration: {total}ms");
+
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<_> = env::args().collect();
+
+    if args.len() < 2 {
+        println!("Usage: cargo run /path/to/animation.(gif|webp)")
+    } else {
+        run(Path::new(&args[1]))?;
+    }
+
+    Ok(())
+}
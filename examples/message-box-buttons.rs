@@ -0,0 +1,50 @@
+use sdl3::messagebox::*;
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let sdl_context = sdl3::init()?;
+    let _video_subsystem = sdl_context.video()?;
+
+    let buttons = [
+        ButtonData {
+            flags: MessageBoxButtonFlag::RETURNKEY_DEFAULT,
+            button_id: 0,
+            text: "Save",
+        },
+        ButtonData {
+            flags: MessageBoxButtonFlag::NOTHING,
+            button_id: 1,
+            text: "Don't Save",
+        },
+        ButtonData {
+            flags: MessageBoxButtonFlag::ESCAPEKEY_DEFAULT,
+            button_id: 2,
+            text: "Cancel",
+        },
+    ];
+    let scheme = MessageBoxColorScheme {
+        background: (40, 44, 52),
+        text: (220, 223, 228),
+        button_border: (97, 175, 239),
+        button_background: (60, 64, 72),
+        button_selected: (97, 175, 239),
+    };
+
+    let clicked = show_message_box(
+        MessageBoxFlag::WARNING,
+        &buttons,
+        "Unsaved changes",
+        "Do you want to save your changes before quitting?",
+        None,
+        scheme,
+    )?;
+
+    match clicked {
+        ClickedButton::CustomButton(ButtonData { button_id: 0, .. }) => println!("Saving..."),
+        ClickedButton::CustomButton(ButtonData { button_id: 1, .. }) => {
+            println!("Quitting without saving")
+        }
+        ClickedButton::CustomButton(_) | ClickedButton::CloseButton => println!("Cancelled"),
+    }
+
+    Ok(())
+}
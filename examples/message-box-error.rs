@@ -0,0 +1,26 @@
+use sdl3::messagebox::{show_simple, MessageBoxLevel};
+use sdl3::surface::Surface;
+
+fn init() -> Result<(), Box<dyn std::error::Error>> {
+    let sdl_context = sdl3::init()?;
+    let _video_subsystem = sdl_context.video()?;
+
+    // This asset doesn't exist, so startup fails.
+    let _splash = Surface::load_bmp("assets/does-not-exist.bmp")?;
+
+    Ok(())
+}
+
+pub fn main() {
+    if let Err(e) = init() {
+        eprintln!("Initialization failed: {e}");
+        show_simple(
+            MessageBoxLevel::Error,
+            "Initialization failed",
+            &format!("The game couldn't start:\n{e}"),
+            None,
+        )
+        .ok();
+        std::process::exit(1);
+    }
+}
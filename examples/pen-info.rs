@@ -0,0 +1,44 @@
+use sdl3::event::Event;
+use sdl3::pen::{Pen, PenAxis};
+use std::collections::{BTreeMap, HashSet};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let sdl_context = sdl3::init()?;
+    let video_subsystem = sdl_context.video()?;
+
+    let _window = video_subsystem
+        .window("rust-sdl3 demo: Pen info", 800, 600)
+        .position_centered()
+        .build()?;
+
+    println!("Bring a pen near the window to list it, close the window to quit");
+
+    // SDL can't list pens up front or report their capabilities, so collect
+    // the axes each pen actually reports.
+    let mut pens: BTreeMap<u32, HashSet<PenAxis>> = BTreeMap::new();
+
+    let mut event_pump = sdl_context.event_pump()?;
+    for event in event_pump.wait_iter() {
+        match event {
+            Event::PenProximityIn { which, .. } => {
+                let pen = Pen::from_id(which);
+                println!("Pen {which} in proximity: {:?}", pen.device_type());
+                pens.entry(which).or_default();
+            }
+            Event::PenAxis { which, axis, .. } if axis != PenAxis::Unknown => {
+                if pens.entry(which).or_default().insert(axis) {
+                    println!("Pen {which} supports {axis:?}");
+                }
+            }
+            Event::PenProximityOut { which, .. } => println!("Pen {which} left proximity"),
+            Event::Quit { .. } => break,
+            _ => (),
+        }
+    }
+
+    for (which, axes) in &pens {
+        println!("Pen {which}: {axes:?}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use sdl3::event::{Event, WindowEvent};
+use sdl3::keyboard::Keycode;
+use std::time::Duration;
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let sdl_context = sdl3::init()?;
+    let video_subsystem = sdl_context.video()?;
+
+    let mut window = video_subsystem
+        .window("Relative mouse mode", 800, 600)
+        .position_centered()
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let mut events = sdl_context.event_pump()?;
+
+    'running: loop {
+        for event in events.poll_iter() {
+            match event {
+                Event::KeyDown {
+                    keycode: Some(Keycode::Escape),
+                    ..
+                }
+                | Event::Quit { .. } => break 'running,
+                // Capture the mouse while the window is focused, and release it again as soon
+                // as the user switches away so the cursor isn't trapped.
+                Event::Window {
+                    win_event: WindowEvent::FocusGained,
+                    ..
+                } => window.set_relative_mouse_mode(true)?,
+                Event::Window {
+                    win_event: WindowEvent::FocusLost,
+                    ..
+                } => window.set_relative_mouse_mode(false)?,
+                Event::MouseMotion { xrel, yrel, .. } if window.relative_mouse_mode() => {
+                    println!("Relative - X = {xrel:?}, Y = {yrel:?}");
+                }
+                _ => {}
+            }
+        }
+
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    Ok(())
+}
@@ -57,10 +57,10 @@ fn main() -> Result<(), String> {
     }
 
     controller
-        .sensor_set_enabled(SensorType::Accelerometer, true)
+        .set_sensor_enabled(SensorType::Accelerometer, true)
         .map_err(|e| format!("error enabling accelerometer: {}", e))?;
     controller
-        .sensor_set_enabled(SensorType::Gyroscope, true)
+        .set_sensor_enabled(SensorType::Gyroscope, true)
         .map_err(|e| format!("error enabling gyroscope: {}", e))?;
     let mut now = Instant::now();
     for event in sdl_context.event_pump().unwrap().wait_iter() {
@@ -71,10 +71,10 @@ fn main() -> Result<(), String> {
             let mut accel_data = [0f32; 3];
 
             controller
-                .sensor_get_data(SensorType::Gyroscope, &mut gyro_data)
+                .get_sensor_data(SensorType::Gyroscope, &mut gyro_data)
                 .map_err(|e| format!("error getting gyro data: {}", e))?;
             controller
-                .sensor_get_data(SensorType::Accelerometer, &mut accel_data)
+                .get_sensor_data(SensorType::Accelerometer, &mut accel_data)
                 .map_err(|e| format!("error getting accel data: {}", e))?;
 
             println!("gyro: {:?}, accel: {:?}", gyro_data, accel_data);
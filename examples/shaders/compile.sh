@@ -7,4 +7,6 @@ glslc -O cube-texture.frag -o cube-texture.frag.spv
 glslc -O cube-texture.vert -o cube-texture.vert.spv
 glslc -O particles.comp -o particles.comp.spv
 glslc -O particles.vert -o particles.vert.spv
-glslc -O particles.frag -o particles.frag.spv
\ No newline at end of file
+glslc -O particles.frag -o particles.frag.spv
+glslc -O compute-fill.comp -o compute-fill.comp.spv
+glslc -O compute-double.comp -o compute-double.comp.spv
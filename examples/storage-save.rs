@@ -0,0 +1,28 @@
+use sdl3::storage::Storage;
+use std::time::Duration;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let _sdl_context = sdl3::init()?;
+
+    let storage = Storage::open_user("rust-sdl3", "storage-example", None)?;
+
+    // User storage may need to be mounted before it can be used.
+    while !storage.ready() {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    let save = b"level=3\nscore=12345\n";
+    storage.write_file("save.txt", save)?;
+    println!("Wrote {} bytes to save.txt", save.len());
+
+    let loaded = storage.read_file("save.txt")?;
+    println!("Read back:\n{}", String::from_utf8_lossy(&loaded));
+    assert_eq!(loaded, save);
+
+    println!("{} bytes of space remaining", storage.space_remaining());
+
+    // Closing explicitly reports whether the writes were committed.
+    storage.close()?;
+
+    Ok(())
+}
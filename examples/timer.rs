@@ -0,0 +1,20 @@
+use sdl3::timer;
+use std::time::Duration;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let _sdl_context = sdl3::init()?;
+
+    let start = timer::ticks();
+    let mut count = 0;
+
+    // Print a tick every 100ms, stopping after one second.
+    let _timer = timer::add_interval_timer(Duration::from_millis(100), move || {
+        count += 1;
+        println!("tick {count} at {}ms", timer::ticks() - start);
+        (count < 10).then_some(Duration::from_millis(100))
+    });
+
+    std::thread::sleep(Duration::from_millis(1100));
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use std::env;
+use std::path::Path;
+
+use sdl3::event::Event;
+use sdl3::keyboard::Keycode;
+use sdl3::pixels::Color;
+use sdl3::timer;
+use sdl3::ttf::TextEngine;
+
+fn run(font_path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let sdl_context = sdl3::init()?;
+    let video_subsys = sdl_context.video()?;
+    let ttf_context = sdl3::ttf::init()?;
+
+    let window = video_subsys
+        .window("SDL3_TTF FPS counter", 400, 120)
+        .position_centered()
+        .build()?;
+
+    let mut canvas = window.into_canvas();
+    let texture_creator = canvas.texture_creator();
+    let font = ttf_context.load_font(font_path, 32.0)?;
+
+    // The text engine caches glyphs, so changing the string every frame is cheap.
+    let engine = TextEngine::new(&texture_creator)?;
+    let mut text = engine.create_text(&font, "FPS: --")?;
+    text.set_color(Color::WHITE)?;
+
+    let frequency = timer::performance_frequency() as f64;
+    let mut last = timer::performance_counter();
+    let mut event_pump = sdl_context.event_pump()?;
+
+    'mainloop: loop {
+        for event in event_pump.poll_iter() {
+            match event {
+                Event::KeyDown {
+                    keycode: Some(Keycode::Escape),
+                    ..
+                }
+                | Event::Quit { .. } => break 'mainloop,
+                _ => {}
+            }
+        }
+
+        let now = timer::performance_counter();
+        let fps = frequency / (now - last).max(1) as f64;
+        last = now;
+        text.set_text(&format!("FPS: {fps:.0}"))?;
+
+        canvas.set_draw_color(Color::RGB(30, 30, 40));
+        canvas.clear();
+        let (w, h) = text.size();
+        let (cw, ch) = canvas.output_size()?;
+        text.draw((cw as f32 - w as f32) / 2.0, (ch as f32 - h as f32) / 2.0)?;
+        canvas.present();
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<_> = env::args().collect();
+
+    if args.len() < 2 {
+        println!("Usage: ./ttf-fps font.[ttf|ttc|fon]")
+    } else {
+        run(Path::new(&args[1]))?;
+    }
+
+    Ok(())
+}
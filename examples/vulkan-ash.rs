@@ -0,0 +1,61 @@
+/// Minimal example creating a Vulkan surface for an SDL window with `ash`.
+/// For your own code, make sure to add "ash" to the features list of sdl3
+use std::ffi::CString;
+
+use ash::vk;
+use sdl3::event::Event;
+use sdl3::keyboard::Keycode;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let sdl_context = sdl3::init()?;
+    let video_subsystem = sdl_context.video()?;
+    let window = video_subsystem
+        .window("rust-sdl3 demo: Vulkan (ash)", 800, 600)
+        .position_centered()
+        .vulkan()
+        .build()?;
+
+    // The instance extensions SDL needs to create a surface for this window
+    let extensions = window.vulkan_instance_extensions()?;
+    println!("Required instance extensions: {extensions:?}");
+    let extensions = extensions
+        .into_iter()
+        .map(CString::new)
+        .collect::<Result<Vec<_>, _>>()?;
+    let extension_ptrs: Vec<_> = extensions.iter().map(|ext| ext.as_ptr()).collect();
+
+    let entry = unsafe { ash::Entry::load()? };
+    let app_name = c"rust-sdl3 vulkan-ash";
+    let app_info = vk::ApplicationInfo::default()
+        .application_name(app_name)
+        .api_version(vk::API_VERSION_1_0);
+    let create_info = vk::InstanceCreateInfo::default()
+        .application_info(&app_info)
+        .enabled_extension_names(&extension_ptrs);
+    let instance = unsafe { entry.create_instance(&create_info, None)? };
+
+    let surface = unsafe { window.vulkan_create_surface(instance.handle())? };
+    println!("Created surface {surface:?}");
+
+    let mut event_pump = sdl_context.event_pump()?;
+    'running: loop {
+        for event in event_pump.poll_iter() {
+            match event {
+                Event::Quit { .. }
+                | Event::KeyDown {
+                    keycode: Some(Keycode::Escape),
+                    ..
+                } => break 'running,
+                _ => {}
+            }
+        }
+        ::std::thread::sleep(::std::time::Duration::from_millis(16));
+    }
+
+    unsafe {
+        ash::khr::surface::Instance::new(&entry, &instance).destroy_surface(surface, None);
+        instance.destroy_instance(None);
+    }
+
+    Ok(())
+}
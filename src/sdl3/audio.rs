@@ -90,6 +90,32 @@ impl AudioSubsystem {
         })
     }
 
+    /// Enumerate audio playback devices along with their names and preferred formats.
+    ///
+    /// Devices that can't be queried, for example because they were unplugged
+    /// during enumeration, are skipped.
+    #[doc(alias = "SDL_GetAudioPlaybackDevices")]
+    pub fn playback_devices(&self) -> Result<Vec<AudioDeviceInfo>, Error> {
+        Ok(self
+            .audio_playback_device_ids()?
+            .into_iter()
+            .filter_map(|id| AudioDeviceInfo::query(id).ok())
+            .collect())
+    }
+
+    /// Enumerate audio recording devices along with their names and preferred formats.
+    ///
+    /// Devices that can't be queried, for example because they were unplugged
+    /// during enumeration, are skipped.
+    #[doc(alias = "SDL_GetAudioRecordingDevices")]
+    pub fn recording_devices(&self) -> Result<Vec<AudioDeviceInfo>, Error> {
+        Ok(self
+            .audio_recording_device_ids()?
+            .into_iter()
+            .filter_map(|id| AudioDeviceInfo::query(id).ok())
+            .collect())
+    }
+
     fn audio_device_ids<F>(&self, get_devices: F) -> Result<Vec<AudioDeviceID>, Error>
     where
         F: FnOnce(&mut i32) -> *mut sys::audio::SDL_AudioDeviceID,
@@ -177,6 +203,48 @@ impl AudioSubsystem {
         device.open_playback_stream_with_callback(spec, callback)
     }
 
+    /// Opens the default playback device with a classic "pull" callback, like SDL2's audio
+    /// callback: `callback` is asked to fill a buffer of samples whenever the device needs more.
+    ///
+    /// The device begins paused, so you must call `resume()` on the result to start playback.
+    /// The callback is freed when the returned stream is dropped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl3::audio::{AudioFormat, AudioPullCallback, AudioSpec};
+    ///
+    /// struct Silence;
+    ///
+    /// impl AudioPullCallback<f32> for Silence {
+    ///     fn callback(&mut self, out: &mut [f32]) {
+    ///         out.fill(0.0);
+    ///     }
+    /// }
+    ///
+    /// let sdl_context = sdl3::init().unwrap();
+    /// let audio_subsystem = sdl_context.audio().unwrap();
+    /// let spec = AudioSpec::new(Some(48_000), Some(1), Some(AudioFormat::f32_sys()));
+    /// let device = audio_subsystem.open_playback_pull_stream(&spec, Silence).unwrap();
+    /// device.resume().unwrap();
+    /// ```
+    pub fn open_playback_pull_stream<CB, Channel>(
+        &self,
+        spec: &AudioSpec,
+        callback: CB,
+    ) -> Result<AudioStreamWithCallback<AudioPullAdapter<CB, Channel>>, Error>
+    where
+        CB: AudioPullCallback<Channel>,
+        Channel: AudioFormatNum + Send + 'static,
+    {
+        self.open_playback_stream::<_, Channel>(
+            spec,
+            AudioPullAdapter {
+                callback,
+                buffer: Vec::new(),
+            },
+        )
+    }
+
     pub fn open_recording_stream<CB, Channel>(
         &self,
         spec: &AudioSpec,
@@ -446,6 +514,23 @@ pub fn drivers() -> DriverIterator {
     }
 }
 
+/// Loads a WAVE file into memory, returning its format and a copy of the decoded samples.
+///
+/// This is a dependency-free way to load sound effects without the `mixer` feature; the
+/// returned bytes can be fed straight into an `AudioStream` opened with the returned spec.
+#[doc(alias = "SDL_LoadWAV")]
+pub fn load_wav<P: AsRef<Path>>(path: P) -> Result<(AudioSpec, Vec<u8>), Error> {
+    AudioSpecWAV::load_wav(path).map(AudioSpecWAV::into_spec_and_buffer)
+}
+
+/// Loads WAVE data from an `IOStream`, returning its format and a copy of the decoded samples.
+///
+/// The stream is not closed.
+#[doc(alias = "SDL_LoadWAV_IO")]
+pub fn load_wav_io(src: &mut IOStream) -> Result<(AudioSpec, Vec<u8>), Error> {
+    AudioSpecWAV::load_wav_rw(src).map(AudioSpecWAV::into_spec_and_buffer)
+}
+
 pub struct AudioSpecWAV {
     pub freq: i32,
     pub format: AudioFormat,
@@ -501,6 +586,20 @@ impl AudioSpecWAV {
             from_raw_parts(ptr, len)
         }
     }
+
+    /// The format of the loaded audio.
+    pub fn spec(&self) -> AudioSpec {
+        AudioSpec::new(
+            Some(self.freq),
+            Some(self.channels as i32),
+            Some(self.format),
+        )
+    }
+
+    /// Copies the samples out of the SDL-allocated buffer, which is freed afterwards.
+    fn into_spec_and_buffer(self) -> (AudioSpec, Vec<u8>) {
+        (self.spec(), self.buffer().to_vec())
+    }
 }
 
 impl Drop for AudioSpecWAV {
@@ -519,6 +618,60 @@ where
     fn callback(&mut self, stream: &mut AudioStream, requested: i32);
 }
 
+/// A classic "pull" audio callback, see [`AudioSubsystem::open_playback_pull_stream`].
+pub trait AudioPullCallback<Channel>: Send + 'static
+where
+    Channel: AudioFormatNum + 'static,
+{
+    /// Fill `out` with the next samples to play. Channels are interleaved.
+    fn callback(&mut self, out: &mut [Channel]);
+}
+
+/// Adapts an [`AudioPullCallback`] to an [`AudioCallback`] by handing it a sample buffer and
+/// queueing the result on the stream.
+///
+/// Dereferences to the wrapped callback, so its state stays reachable through
+/// `AudioStreamWithCallback::lock`.
+pub struct AudioPullAdapter<CB, Channel> {
+    callback: CB,
+    buffer: Vec<Channel>,
+}
+
+impl<CB, Channel> AudioCallback<Channel> for AudioPullAdapter<CB, Channel>
+where
+    CB: AudioPullCallback<Channel>,
+    Channel: AudioFormatNum + Send + 'static,
+{
+    fn callback(&mut self, stream: &mut AudioStream, requested: i32) {
+        self.buffer
+            .resize(requested.max(0) as usize, Channel::SILENCE);
+        self.callback.callback(&mut self.buffer);
+
+        let bytes = unsafe {
+            slice::from_raw_parts(
+                self.buffer.as_ptr() as *const u8,
+                std::mem::size_of_val(self.buffer.as_slice()),
+            )
+        };
+        // There is nobody to report a failure to from the audio thread.
+        let _ = stream.put_data(bytes);
+    }
+}
+
+impl<CB, Channel> Deref for AudioPullAdapter<CB, Channel> {
+    type Target = CB;
+
+    fn deref(&self) -> &CB {
+        &self.callback
+    }
+}
+
+impl<CB, Channel> DerefMut for AudioPullAdapter<CB, Channel> {
+    fn deref_mut(&mut self) -> &mut CB {
+        &mut self.callback
+    }
+}
+
 /// A phantom type for retrieving the `SDL_AudioFormat` of a given generic type.
 /// All format types are returned as native-endian.
 pub trait AudioFormatNum: Copy + 'static {
@@ -706,6 +859,47 @@ impl AudioDeviceID {
     }
 }
 
+/// Description of an audio device, as returned by [`AudioSubsystem::playback_devices`] and
+/// [`AudioSubsystem::recording_devices`].
+#[derive(Clone, Debug)]
+pub struct AudioDeviceInfo {
+    /// The device instance id, usable with [`AudioDevice::open_playback`] or
+    /// [`AudioDevice::open_recording`].
+    pub id: AudioDeviceID,
+    /// Human readable device name.
+    pub name: String,
+    /// The format the device prefers. Opening it with this spec avoids any conversion.
+    pub spec: AudioSpec,
+    /// The device's preferred buffer size in sample frames, if known.
+    pub sample_frames: Option<i32>,
+}
+
+impl AudioDeviceInfo {
+    #[doc(alias = "SDL_GetAudioDeviceName")]
+    #[doc(alias = "SDL_GetAudioDeviceFormat")]
+    fn query(id: AudioDeviceID) -> Result<AudioDeviceInfo, Error> {
+        let name = id.name()?;
+        let mut raw_spec = AudioSpec::default().into();
+        let mut sample_frames: c_int = 0;
+        let result = unsafe {
+            sys::audio::SDL_GetAudioDeviceFormat(id.id(), &mut raw_spec, &mut sample_frames)
+        };
+        if !result {
+            return Err(get_error());
+        }
+        Ok(AudioDeviceInfo {
+            id,
+            name,
+            spec: AudioSpec::from(&raw_spec),
+            sample_frames: if sample_frames > 0 {
+                Some(sample_frames)
+            } else {
+                None
+            },
+        })
+    }
+}
+
 /// Represents an open audio device (playback or recording).
 #[derive(Clone)]
 pub struct AudioDevice {
@@ -963,6 +1157,16 @@ impl AudioDevice {
         unsafe { sys::audio::SDL_ResumeAudioDevice(self.device_id.id()) }
     }
 
+    /// Returns `true` if the audio device is paused.
+    ///
+    /// Devices opened with [`AudioDevice::open_playback`] and friends start unpaused, while
+    /// devices opened alongside a stream start paused. Unopened or invalid devices report
+    /// `false`.
+    #[doc(alias = "SDL_AudioDevicePaused")]
+    pub fn is_paused(&self) -> bool {
+        unsafe { sys::audio::SDL_AudioDevicePaused(self.device_id.id()) }
+    }
+
     /// Opens a new audio stream for this device with the specified spec.
     /// The device begins paused, so you must call `stream.resume()` to start playback.
     #[doc(alias = "SDL_OpenAudioDeviceStream")]
@@ -1087,6 +1291,43 @@ pub struct AudioStream {
     stream: *mut sys::audio::SDL_AudioStream,
 }
 
+impl AudioStreamOwner {
+    /// Creates a standalone audio stream that converts audio data from `src_spec` to
+    /// `dst_spec`, e.g. for resampling or changing the sample format or channel count.
+    ///
+    /// Unlike [`AudioSubsystem::new_stream`], this does not require the audio subsystem to be
+    /// initialized, since the stream is not bound to any device.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl3::audio::{AudioFormat, AudioSpec, AudioStreamOwner};
+    ///
+    /// let src = AudioSpec::new(Some(44_100), Some(1), Some(AudioFormat::f32_sys()));
+    /// let dst = AudioSpec::new(Some(48_000), Some(2), Some(AudioFormat::s16_sys()));
+    /// let stream = AudioStreamOwner::new(&src, &dst).unwrap();
+    ///
+    /// stream.put_data_f32(&[0.0; 441]).unwrap();
+    /// stream.flush().unwrap();
+    ///
+    /// let mut out = vec![0u8; stream.available_bytes().unwrap() as usize];
+    /// let read = stream.get_data(&mut out).unwrap();
+    /// ```
+    #[doc(alias = "SDL_CreateAudioStream")]
+    pub fn new(src_spec: &AudioSpec, dst_spec: &AudioSpec) -> Result<AudioStreamOwner, Error> {
+        let sdl_src_spec = sys::audio::SDL_AudioSpec::from(src_spec);
+        let sdl_dst_spec = sys::audio::SDL_AudioSpec::from(dst_spec);
+        let stream = unsafe { sys::audio::SDL_CreateAudioStream(&sdl_src_spec, &sdl_dst_spec) };
+        if stream.is_null() {
+            Err(get_error())
+        } else {
+            Ok(AudioStreamOwner {
+                inner: AudioStream { stream },
+                audio_subsystem: None,
+            })
+        }
+    }
+}
+
 impl Deref for AudioStreamOwner {
     type Target = AudioStream;
 
@@ -1449,6 +1690,26 @@ impl AudioStream {
         }
     }
 
+    /// Gets converted/resampled data from the stream, in the stream's output format.
+    ///
+    /// Returns the number of bytes written to `buf`, which may be less than its length if
+    /// not enough data is available.
+    #[doc(alias = "SDL_GetAudioStreamData")]
+    pub fn get_data(&self, buf: &mut [u8]) -> Result<usize, Error> {
+        let ret = unsafe {
+            sys::audio::SDL_GetAudioStreamData(
+                self.stream,
+                buf.as_mut_ptr().cast(),
+                buf.len() as c_int,
+            )
+        };
+        if ret == -1 {
+            Err(get_error())
+        } else {
+            Ok(ret as usize)
+        }
+    }
+
     /// Adds data to the stream (16-bit signed).
     pub fn put_data_i16(&self, buf: &[i16]) -> Result<(), Error> {
         let result = unsafe {
@@ -1488,18 +1749,7 @@ impl Read for AudioStream {
     /// You must convert the bytes to samples based on the format of the stream.
     /// `read_f32_samples` and `read_i16_samples` are provided for convenience.
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-        let ret = unsafe {
-            sys::audio::SDL_GetAudioStreamData(
-                self.stream,
-                buf.as_mut_ptr().cast(),
-                buf.len() as c_int,
-            )
-        };
-        if ret == -1 {
-            Err(io::Error::other(get_error()))
-        } else {
-            Ok(ret as usize)
-        }
+        self.get_data(buf).map_err(io::Error::other)
     }
 }
 
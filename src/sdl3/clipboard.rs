@@ -30,10 +30,13 @@ impl crate::VideoSubsystem {
 }
 
 impl ClipboardUtil {
+    /// Put `text` on the clipboard.
+    ///
+    /// Fails if `text` contains a NUL byte.
     #[doc(alias = "SDL_SetClipboardText")]
     pub fn set_clipboard_text(&self, text: &str) -> Result<(), Error> {
+        let text = text_to_c_string(text)?;
         unsafe {
-            let text = CString::new(text).unwrap();
             let result = sys::clipboard::SDL_SetClipboardText(text.as_ptr() as *const c_char);
 
             if !result {
@@ -44,30 +47,120 @@ impl ClipboardUtil {
         }
     }
 
+    /// Get the text on the clipboard, or an empty string if there is none.
+    ///
+    /// Invalid UTF-8 is replaced with `U+FFFD`.
     #[doc(alias = "SDL_GetClipboardText")]
     pub fn clipboard_text(&self) -> Result<String, Error> {
+        unsafe { owned_sdl_string(sys::clipboard::SDL_GetClipboardText()) }
+    }
+
+    /// Return whether the clipboard holds non-empty text.
+    #[doc(alias = "SDL_HasClipboardText")]
+    pub fn has_clipboard_text(&self) -> bool {
+        unsafe { sys::clipboard::SDL_HasClipboardText() }
+    }
+
+    /// Offer arbitrary data on the clipboard in each of `mime_types`.
+    ///
+    /// `provider` is called lazily with the requested mime type whenever
+    /// someone pastes, and returns the bytes for it. SDL may call it, and drop
+    /// it once the clipboard is replaced or cleared, from another thread.
+    ///
+    /// ```no_run
+    /// let sdl_context = sdl3::init().unwrap();
+    /// let clipboard = sdl_context.video().unwrap().clipboard();
+    ///
+    /// clipboard
+    ///     .set_clipboard_data(&["text/html", "text/plain"], |mime_type| match mime_type {
+    ///         "text/html" => b"<b>Hello</b>".to_vec(),
+    ///         _ => b"Hello".to_vec(),
+    ///     })
+    ///     .unwrap();
+    /// ```
+    #[doc(alias = "SDL_SetClipboardData")]
+    pub fn set_clipboard_data<F>(&self, mime_types: &[&str], provider: F) -> Result<(), Error>
+    where
+        F: Fn(&str) -> Vec<u8> + Send + 'static,
+    {
+        if mime_types.is_empty() {
+            return Err(Error("at least one mime type is required".to_owned()));
+        }
+        let mime_types = mime_types
+            .iter()
+            .map(|mime_type| {
+                CString::new(*mime_type)
+                    .map_err(|_| Error("mime types must not contain NUL bytes".to_owned()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut mime_type_ptrs: Vec<*const c_char> = mime_types
+            .iter()
+            .map(|mime_type| mime_type.as_ptr())
+            .collect();
+
+        let userdata = Box::into_raw(Box::new(ClipboardProvider {
+            provider: Box::new(provider),
+            data: Vec::new(),
+        }));
+
+        // SDL copies the mime types, so they only need to outlive this call.
+        let result = unsafe {
+            sys::clipboard::SDL_SetClipboardData(
+                Some(clipboard_data_callback),
+                Some(clipboard_cleanup_callback),
+                userdata as *mut c_void,
+                mime_type_ptrs.as_mut_ptr(),
+                mime_type_ptrs.len(),
+            )
+        };
+
+        if result {
+            Ok(())
+        } else {
+            // Past parameter validation SDL owns `userdata`, and frees it
+            // through the cleanup callback even when this call fails.
+            Err(get_error())
+        }
+    }
+
+    /// Get the clipboard contents in `mime_type`.
+    #[doc(alias = "SDL_GetClipboardData")]
+    pub fn clipboard_data(&self, mime_type: &str) -> Result<Vec<u8>, Error> {
+        let mime_type = CString::new(mime_type)
+            .map_err(|_| Error("mime types must not contain NUL bytes".to_owned()))?;
+        let mut size = 0;
         unsafe {
-            let buf = sys::clipboard::SDL_GetClipboardText();
+            let buf = sys::clipboard::SDL_GetClipboardData(mime_type.as_ptr(), &mut size);
 
             if buf.is_null() {
                 Err(get_error())
             } else {
-                let s = CStr::from_ptr(buf as *const _).to_str().unwrap().to_owned();
-                sys::stdinc::SDL_free(buf as *mut c_void);
-                Ok(s)
+                let data = std::slice::from_raw_parts(buf as *const u8, size).to_vec();
+                sys::stdinc::SDL_free(buf);
+                Ok(data)
             }
         }
     }
 
-    #[doc(alias = "SDL_HasClipboardText")]
-    pub fn has_clipboard_text(&self) -> bool {
-        unsafe { sys::clipboard::SDL_HasClipboardText() }
+    /// Return whether the clipboard has data in `mime_type`.
+    #[doc(alias = "SDL_HasClipboardData")]
+    pub fn has_clipboard_data(&self, mime_type: &str) -> bool {
+        match CString::new(mime_type) {
+            Ok(mime_type) => unsafe { sys::clipboard::SDL_HasClipboardData(mime_type.as_ptr()) },
+            Err(_) => false,
+        }
     }
 
+    /// Put `text` in the primary selection, the buffer pasted with a
+    /// middle click on X11 and Wayland.
+    ///
+    /// The primary selection is separate from the clipboard. On platforms
+    /// without one, this only updates a buffer local to SDL. Fails if `text`
+    /// contains a NUL byte.
     #[doc(alias = "SDL_SetPrimarySelectionText")]
     pub fn set_primary_selection_text(&self, text: &str) -> Result<(), Error> {
+        let text = text_to_c_string(text)?;
         unsafe {
-            let text = CString::new(text).unwrap();
             let result =
                 sys::clipboard::SDL_SetPrimarySelectionText(text.as_ptr() as *const c_char);
 
@@ -79,23 +172,63 @@ impl ClipboardUtil {
         }
     }
 
+    /// Get the text in the primary selection, or an empty string if there
+    /// is none.
+    ///
+    /// Invalid UTF-8 is replaced with `U+FFFD`.
     #[doc(alias = "SDL_GetPrimarySelectionText")]
     pub fn primary_selection_text(&self) -> Result<String, Error> {
-        unsafe {
-            let buf = sys::clipboard::SDL_GetPrimarySelectionText();
-
-            if buf.is_null() {
-                Err(get_error())
-            } else {
-                let s = CStr::from_ptr(buf as *const _).to_str().unwrap().to_owned();
-                sys::stdinc::SDL_free(buf as *mut c_void);
-                Ok(s)
-            }
-        }
+        unsafe { owned_sdl_string(sys::clipboard::SDL_GetPrimarySelectionText()) }
     }
 
+    /// Return whether the primary selection holds non-empty text.
     #[doc(alias = "SDL_HasPrimarySelectionText")]
     pub fn has_primary_selection_text(&self) -> bool {
         unsafe { sys::clipboard::SDL_HasPrimarySelectionText() }
     }
 }
+
+fn text_to_c_string(text: &str) -> Result<CString, Error> {
+    CString::new(text).map_err(|_| Error("clipboard text must not contain NUL bytes".to_owned()))
+}
+
+/// Copy and free a string allocated by SDL. NULL means SDL reported an error.
+unsafe fn owned_sdl_string(buf: *mut c_char) -> Result<String, Error> {
+    if buf.is_null() {
+        Err(get_error())
+    } else {
+        let s = CStr::from_ptr(buf as *const _)
+            .to_string_lossy()
+            .into_owned();
+        sys::stdinc::SDL_free(buf as *mut c_void);
+        Ok(s)
+    }
+}
+
+struct ClipboardProvider {
+    provider: Box<dyn Fn(&str) -> Vec<u8> + Send>,
+    /// SDL doesn't take ownership of the returned data, so the last answer
+    /// is kept alive here until the next request or cleanup.
+    data: Vec<u8>,
+}
+
+unsafe extern "C" fn clipboard_data_callback(
+    userdata: *mut c_void,
+    mime_type: *const c_char,
+    size: *mut usize,
+) -> *const c_void {
+    let provider = &mut *(userdata as *mut ClipboardProvider);
+    let mime_type = if mime_type.is_null() {
+        std::borrow::Cow::Borrowed("")
+    } else {
+        CStr::from_ptr(mime_type).to_string_lossy()
+    };
+
+    provider.data = (provider.provider)(&mime_type);
+    *size = provider.data.len();
+    provider.data.as_ptr() as *const c_void
+}
+
+unsafe extern "C" fn clipboard_cleanup_callback(userdata: *mut c_void) {
+    drop(Box::from_raw(userdata as *mut ClipboardProvider));
+}
@@ -1,4 +1,7 @@
+use crate::get_error;
 use crate::sys;
+use crate::Error;
+use std::ptr::NonNull;
 
 pub const CACHELINESIZE: u8 = 128;
 
@@ -87,7 +90,115 @@ pub fn system_ram() -> i32 {
     unsafe { sys::cpuinfo::SDL_GetSystemRAM() }
 }
 
+/// Returns the alignment in bytes needed for the SIMD instructions available
+/// on this CPU.
 #[doc(alias = "SDL_GetSIMDAlignment")]
 pub fn simd_alignment() -> usize {
     unsafe { sys::cpuinfo::SDL_GetSIMDAlignment() }
 }
+
+/// A zero-initialized byte buffer aligned to [`simd_alignment`], freed on
+/// drop.
+pub struct SimdAlloc {
+    ptr: NonNull<u8>,
+    len: usize,
+}
+
+// The buffer is owned, like a `Box<[u8]>`.
+unsafe impl Send for SimdAlloc {}
+unsafe impl Sync for SimdAlloc {}
+
+impl SimdAlloc {
+    /// Allocates `len` zeroed bytes.
+    #[doc(alias = "SDL_aligned_alloc")]
+    pub fn new(len: usize) -> Result<SimdAlloc, Error> {
+        // SDL may return NULL for an empty allocation, so always ask for a byte.
+        let ptr = unsafe { sys::stdinc::SDL_aligned_alloc(simd_alignment(), len.max(1)) };
+        let Some(ptr) = NonNull::new(ptr as *mut u8) else {
+            return Err(get_error());
+        };
+        unsafe { ptr.as_ptr().write_bytes(0, len) };
+        Ok(SimdAlloc { ptr, len })
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn as_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr()
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.ptr.as_ptr()
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl Drop for SimdAlloc {
+    #[doc(alias = "SDL_aligned_free")]
+    fn drop(&mut self) {
+        unsafe { sys::stdinc::SDL_aligned_free(self.ptr.as_ptr() as *mut _) }
+    }
+}
+
+/// A snapshot of the CPU and memory details reported by the functions of
+/// this module, to pick SIMD code paths or size a thread pool in one go.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CpuInfo {
+    /// Number of logical CPU cores, including hyperthreads.
+    pub logical_cores: i32,
+    /// L1 cache line size in bytes.
+    pub cache_line_size: i32,
+    /// Amount of system RAM in MiB.
+    pub system_ram: i32,
+    pub alti_vec: bool,
+    pub mmx: bool,
+    pub sse: bool,
+    pub sse2: bool,
+    pub sse3: bool,
+    pub sse41: bool,
+    pub sse42: bool,
+    pub avx: bool,
+    pub avx2: bool,
+    pub avx512f: bool,
+    pub arm_simd: bool,
+    pub neon: bool,
+    pub lsx: bool,
+    pub lasx: bool,
+}
+
+impl CpuInfo {
+    pub fn get() -> CpuInfo {
+        CpuInfo {
+            logical_cores: num_logical_cpu_cores(),
+            cache_line_size: cpu_cache_line_size(),
+            system_ram: system_ram(),
+            alti_vec: has_alti_vec(),
+            mmx: has_mmx(),
+            sse: has_sse(),
+            sse2: has_sse2(),
+            sse3: has_sse3(),
+            sse41: has_sse41(),
+            sse42: has_sse42(),
+            avx: has_avx(),
+            avx2: has_avx2(),
+            avx512f: has_avx512f(),
+            arm_simd: has_arm_simd(),
+            neon: has_neon(),
+            lsx: has_lsx(),
+            lasx: has_lasx(),
+        }
+    }
+}
@@ -12,12 +12,22 @@ use sys::dialog::SDL_DialogFileFilter;
 
 use crate::video::Window;
 
-#[derive(Debug)]
+/// A named set of file patterns offered by the open and save dialogs.
+///
+/// `pattern` is a `;` separated list of extensions without the leading dot,
+/// such as `"png;jpg;bmp"`, or `"*"` to match every file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DialogFileFilter<'a> {
     pub name: &'a str,
     pub pattern: &'a str,
 }
 
+impl<'a> DialogFileFilter<'a> {
+    pub const fn new(name: &'a str, pattern: &'a str) -> DialogFileFilter<'a> {
+        DialogFileFilter { name, pattern }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum DialogError {
     FilterError(NulError),
@@ -39,97 +49,116 @@ impl fmt::Display for DialogError {
     }
 }
 
-pub type DialogCallback = Box<dyn Fn(Result<Vec<PathBuf>, DialogError>, Option<DialogFileFilter>)>;
+impl std::error::Error for DialogError {
+    fn description(&self) -> &str {
+        use self::DialogError::*;
+
+        match *self {
+            FilterError(_) => "invalid filter",
+            InvalidFilename(_) => "invalid filename",
+            Canceled => "canceled",
+            SdlError(ref e) => &e.0,
+        }
+    }
+}
+
+/// Called once with the outcome of a dialog, and the filter the user picked
+/// if the platform reports it.
+///
+/// SDL may invoke the callback from another thread, hence the `Send` bound.
+pub type DialogCallback =
+    Box<dyn FnOnce(Result<Vec<PathBuf>, DialogError>, Option<DialogFileFilter>) + Send + 'static>;
 
 struct DialogCallbackData {
     pub callback: DialogCallback,
-    pub filter_strings: Option<Vec<(CString, CString)>>,
+    pub filters: Option<DialogFilters>,
 }
 
-extern "C" fn c_dialog_callback(
-    userdata: *mut c_void,
-    filelist: *const *const c_char,
-    filter: c_int,
-) {
-    let callback_info_ptr = userdata as *mut DialogCallbackData;
+/// Filters lowered to the C representation SDL expects.
+///
+/// SDL reads the filters asynchronously, so this must stay alive until the
+/// dialog callback runs. The `raw` entries point into the heap buffers of
+/// `strings`, which don't move when the struct does.
+struct DialogFilters {
+    strings: Vec<(CString, CString)>,
+    raw: Vec<SDL_DialogFileFilter>,
+}
+
+impl DialogFilters {
+    fn new(filters: &[DialogFileFilter]) -> Result<DialogFilters, DialogError> {
+        let strings = filters
+            .iter()
+            .map(|filter| Ok((CString::new(filter.name)?, CString::new(filter.pattern)?)))
+            .collect::<Result<Vec<_>, NulError>>()
+            .map_err(DialogError::FilterError)?;
+        let raw = strings
+            .iter()
+            .map(|(name, pattern)| SDL_DialogFileFilter {
+                name: name.as_ptr(),
+                pattern: pattern.as_ptr(),
+            })
+            .collect();
+        Ok(DialogFilters { strings, raw })
+    }
+
+    fn get(&self, index: usize) -> Option<DialogFileFilter<'_>> {
+        let (name, pattern) = self.strings.get(index)?;
+        Some(DialogFileFilter {
+            // We created these from strs, they cannot fail
+            name: name.to_str().unwrap(),
+            pattern: pattern.to_str().unwrap(),
+        })
+    }
+}
+
+/// Convert the NULL-terminated file list SDL hands to dialog callbacks.
+unsafe fn file_list_to_paths(filelist: *const *const c_char) -> Result<Vec<PathBuf>, DialogError> {
     if filelist.is_null() {
-        unsafe {
-            return ((*callback_info_ptr).callback)(Err(DialogError::SdlError(get_error())), None);
-        }
+        return Err(DialogError::SdlError(get_error()));
     }
 
     let mut files = Vec::new();
-    unsafe {
-        let mut count = 0;
-        loop {
-            let file = *filelist.offset(count);
-            if file.is_null() {
-                break;
-            }
-
-            let file = CStr::from_ptr(file);
-            match file.to_str() {
-                // PathBuf::from_str can not fail
-                Ok(file) => files.push(PathBuf::from_str(file).unwrap()),
-                Err(e) => {
-                    return ((*callback_info_ptr).callback)(
-                        Err(DialogError::InvalidFilename(e)),
-                        None,
-                    )
-                }
-            };
-
-            count += 1;
+    let mut count = 0;
+    loop {
+        let file = *filelist.offset(count);
+        if file.is_null() {
+            break;
         }
 
-        if count == 0 {
-            return ((*callback_info_ptr).callback)(Err(DialogError::Canceled), None);
-        }
+        match CStr::from_ptr(file).to_str() {
+            // PathBuf::from_str can not fail
+            Ok(file) => files.push(PathBuf::from_str(file).unwrap()),
+            Err(e) => return Err(DialogError::InvalidFilename(e)),
+        };
+
+        count += 1;
     }
-    unsafe {
-        if filter < 0 {
-            ((*callback_info_ptr).callback)(Ok(files), None)
-        } else {
-            // Seemingly not implemented in linux portals, untested
-            if let Some(filter_strings) = &(*callback_info_ptr).filter_strings {
-                if let Some(filter) = filter_strings.get(filter as usize) {
-                    let filter = DialogFileFilter {
-                        // We created these from strs, they cannot fail
-                        name: filter.0.to_str().unwrap(),
-                        pattern: filter.1.to_str().unwrap(),
-                    };
-                    ((*callback_info_ptr).callback)(Ok(files), Some(filter))
-                }
-            }
-        }
+
+    if files.is_empty() {
+        Err(DialogError::Canceled)
+    } else {
+        Ok(files)
     }
 }
 
-/// Take a slice of DialogFileFilter and transform it into two vecs
-/// The filter_strings vec contains the CStrings.
-///     filter_strings must not be dropped until the callback is complete.
-/// The c_filters vec contains pointers to the CStrings in filter_strings.
-macro_rules! filters {
-    ($filters:ident, $filter_strings:ident, $c_filters:ident) => {
-        let mut $filter_strings = Vec::new();
-        for filter in $filters {
-            match (CString::new(filter.name), CString::new(filter.pattern)) {
-                (Ok(name), Ok(pattern)) => {
-                    $filter_strings.push((name, pattern));
-                }
-                (Err(error), _) | (_, Err(error)) => {
-                    return Err(DialogError::FilterError(error));
-                }
-            }
-        }
-        let $c_filters: Vec<SDL_DialogFileFilter> = $filter_strings
-            .iter()
-            .map(|(name, pattern)| SDL_DialogFileFilter {
-                name: name.as_ptr(),
-                pattern: pattern.as_ptr(),
-            })
-            .collect();
-    };
+extern "C" fn c_dialog_callback(
+    userdata: *mut c_void,
+    filelist: *const *const c_char,
+    filter: c_int,
+) {
+    // SDL invokes this exactly once per dialog, so the boxed data allocated
+    // when showing the dialog is reclaimed and freed here.
+    let data = unsafe { Box::from_raw(userdata as *mut DialogCallbackData) };
+    let DialogCallbackData { callback, filters } = *data;
+
+    let files = unsafe { file_list_to_paths(filelist) };
+
+    // Seemingly not implemented in linux portals, untested
+    let filter = usize::try_from(filter)
+        .ok()
+        .and_then(|index| filters.as_ref()?.get(index));
+
+    callback(files, filter);
 }
 
 /// If an optional window exists get it's pointer, otherwise get a null pointer.
@@ -154,15 +183,39 @@ macro_rules! default_location_ptr {
 }
 
 macro_rules! callback_data_ptr {
-    ($callback:ident, $filter_strings:expr, $callback_data_ptr:ident) => {
+    ($callback:ident, $filters:expr, $callback_data_ptr:ident) => {
         let callback_data = DialogCallbackData {
             callback: $callback,
-            filter_strings: $filter_strings,
+            filters: $filters,
         };
         let $callback_data_ptr = Box::into_raw(Box::new(callback_data));
     };
 }
 
+/// Show an asynchronous dialog to pick one or more existing files.
+///
+/// Returns immediately; `callback` runs once the user is done, which may be
+/// on another thread on some platforms. Events must be pumped while the
+/// dialog is open.
+///
+/// ```no_run
+/// use sdl3::dialog::{show_open_file_dialog, DialogFileFilter};
+/// use sdl3::video::Window;
+/// use std::path::PathBuf;
+///
+/// let filters = [DialogFileFilter::new("Images", "png;jpg;bmp")];
+/// show_open_file_dialog(
+///     &filters,
+///     None::<PathBuf>,
+///     true,
+///     None::<&Window>,
+///     Box::new(|result, _filter| match result {
+///         Ok(paths) => println!("Picked {paths:?}"),
+///         Err(e) => println!("No file picked: {e}"),
+///     }),
+/// )
+/// .unwrap();
+/// ```
 #[doc(alias = "SDL_ShowOpenFileDialog")]
 pub fn show_open_file_dialog<'a, W>(
     filters: &[DialogFileFilter],
@@ -176,19 +229,25 @@ where
 {
     let window = window.into();
 
-    filters!(filters, filter_strings, c_filters);
+    let filters = DialogFilters::new(filters)?;
+    let num_filters = filters.raw.len() as i32;
 
     unsafe {
         window_ptr!(window, window_ptr);
         default_location_ptr!(default_location, default_location_ptr);
-        callback_data_ptr!(callback, Some(filter_strings), callback_data_ptr);
+        callback_data_ptr!(callback, Some(filters), callback_data_ptr);
+        let filters_ptr = (*callback_data_ptr)
+            .filters
+            .as_ref()
+            .filter(|filters| !filters.raw.is_empty())
+            .map_or(ptr::null(), |filters| filters.raw.as_ptr());
 
         sys::dialog::SDL_ShowOpenFileDialog(
             Some(c_dialog_callback),
             callback_data_ptr as *mut c_void,
             window_ptr,
-            c_filters.as_ptr(),
-            c_filters.len() as i32,
+            filters_ptr,
+            num_filters,
             default_location_ptr,
             allow_many,
         );
@@ -196,6 +255,9 @@ where
     }
 }
 
+/// Show an asynchronous dialog to pick one or more existing folders.
+///
+/// See [`show_open_file_dialog`] for how `callback` is invoked.
 #[doc(alias = "SDL_ShowOpenFolderDialog")]
 pub fn show_open_folder_dialog<'a, W>(
     default_location: Option<impl AsRef<Path>>,
@@ -222,6 +284,9 @@ pub fn show_open_folder_dialog<'a, W>(
     }
 }
 
+/// Show an asynchronous dialog to pick a file to save to.
+///
+/// See [`show_open_file_dialog`] for how `callback` is invoked.
 #[doc(alias = "SDL_ShowSaveFileDialog")]
 pub fn show_save_file_dialog<'a, W>(
     filters: &[DialogFileFilter],
@@ -234,21 +299,62 @@ where
 {
     let window = window.into();
 
-    filters!(filters, filter_strings, c_filters);
+    let filters = DialogFilters::new(filters)?;
+    let num_filters = filters.raw.len() as i32;
 
     unsafe {
         window_ptr!(window, window_ptr);
         default_location_ptr!(default_location, default_location_ptr);
-        callback_data_ptr!(callback, Some(filter_strings), callback_data_ptr);
+        callback_data_ptr!(callback, Some(filters), callback_data_ptr);
+        let filters_ptr = (*callback_data_ptr)
+            .filters
+            .as_ref()
+            .filter(|filters| !filters.raw.is_empty())
+            .map_or(ptr::null(), |filters| filters.raw.as_ptr());
 
         sys::dialog::SDL_ShowSaveFileDialog(
             Some(c_dialog_callback),
             callback_data_ptr as *mut c_void,
             window_ptr,
-            c_filters.as_ptr(),
-            c_filters.len() as i32,
+            filters_ptr,
+            num_filters,
             default_location_ptr,
         );
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{DialogFileFilter, DialogFilters};
+    use std::ffi::CStr;
+
+    #[test]
+    fn lowered_filters_point_at_their_strings() {
+        let filters = [
+            DialogFileFilter::new("Images", "png;jpg;bmp"),
+            DialogFileFilter::new("All files", "*"),
+        ];
+        let lowered = DialogFilters::new(&filters).unwrap();
+
+        // Moving the lowered filters must not invalidate the raw pointers.
+        let lowered = Box::new(lowered);
+        assert_eq!(lowered.raw.len(), filters.len());
+        for (raw, filter) in lowered.raw.iter().zip(&filters) {
+            assert!(!raw.name.is_null());
+            assert!(!raw.pattern.is_null());
+            unsafe {
+                assert_eq!(CStr::from_ptr(raw.name).to_str(), Ok(filter.name));
+                assert_eq!(CStr::from_ptr(raw.pattern).to_str(), Ok(filter.pattern));
+            }
+        }
+        assert_eq!(lowered.get(1), Some(filters[1]));
+        assert_eq!(lowered.get(2), None);
+    }
+
+    #[test]
+    fn filters_with_nul_are_rejected() {
+        let filters = [DialogFileFilter::new("Bad\0name", "txt")];
+        assert!(DialogFilters::new(&filters).is_err());
+    }
+}
@@ -126,6 +126,24 @@ impl crate::EventSubsystem {
         self.event_sender().push_event(event)
     }
 
+    /// Pushes an [`Event::Quit`] stamped with the current time, so the main loop
+    /// can shut down cleanly the same way as when the user closes the app.
+    pub fn push_quit(&self) -> Result<(), Error> {
+        self.push_event(Event::Quit {
+            timestamp: crate::timer::ticks_ns(),
+        })
+    }
+
+    /// Pushes a synthetic [`Event::Window`] for the window with id `window_id`,
+    /// stamped with the current time. This is mostly useful in tests.
+    pub fn push_window_event(&self, window_id: u32, win_event: WindowEvent) -> Result<(), Error> {
+        self.push_event(Event::Window {
+            timestamp: crate::timer::ticks_ns(),
+            window_id,
+            win_event,
+        })
+    }
+
     /// Register a custom SDL event.
     ///
     /// When pushing a user event, you must make sure that the ``type_`` field is set to a
@@ -319,6 +337,7 @@ pub enum EventType {
     KeyUp = sys::events::SDL_EVENT_KEY_UP.0,
     TextEditing = sys::events::SDL_EVENT_TEXT_EDITING.0,
     TextInput = sys::events::SDL_EVENT_TEXT_INPUT.0,
+    TextEditingCandidates = sys::events::SDL_EVENT_TEXT_EDITING_CANDIDATES.0,
 
     MouseMotion = sys::events::SDL_EVENT_MOUSE_MOTION.0,
     MouseButtonDown = sys::events::SDL_EVENT_MOUSE_BUTTON_DOWN.0,
@@ -430,6 +449,7 @@ impl TryFrom<u32> for EventType {
             SDL_EVENT_KEY_UP => KeyUp,
             SDL_EVENT_TEXT_EDITING => TextEditing,
             SDL_EVENT_TEXT_INPUT => TextInput,
+            SDL_EVENT_TEXT_EDITING_CANDIDATES => TextEditingCandidates,
 
             SDL_EVENT_MOUSE_MOTION => MouseMotion,
             SDL_EVENT_MOUSE_BUTTON_DOWN => MouseButtonDown,
@@ -702,6 +722,7 @@ pub enum Event {
         keycode: Option<Keycode>,
         scancode: Option<Scancode>,
         keymod: Mod,
+        /// `true` for events generated by key repeat, as reported by SDL
         repeat: bool,
         which: u32,
         raw: Uint16,
@@ -712,6 +733,7 @@ pub enum Event {
         keycode: Option<Keycode>,
         scancode: Option<Scancode>,
         keymod: Mod,
+        /// `true` for events generated by key repeat, as reported by SDL
         repeat: bool,
         which: u32,
         raw: Uint16,
@@ -731,6 +753,19 @@ pub enum Event {
         text: String,
     },
 
+    /// The input method's candidate list changed, for applications drawing
+    /// their own IME candidate popup.
+    TextEditingCandidates {
+        timestamp: u64,
+        window_id: u32,
+        /// The candidates, empty when the popup should be hidden
+        candidates: Vec<String>,
+        /// Index of the selected candidate, or -1 if none is selected
+        selected: i32,
+        /// `true` if the candidates should be laid out horizontally
+        horizontal: bool,
+    },
+
     MouseMotion {
         timestamp: u64,
         window_id: u32,
@@ -1880,6 +1915,27 @@ impl Event {
                         text,
                     }
                 }
+                EventType::TextEditingCandidates => {
+                    let event = raw.edit_candidates;
+
+                    let candidates = if event.candidates.is_null() {
+                        Vec::new()
+                    } else {
+                        let len = event.num_candidates.max(0) as usize;
+                        std::slice::from_raw_parts(event.candidates, len)
+                            .iter()
+                            .map(|&c| CStr::from_ptr(c).to_string_lossy().into_owned())
+                            .collect()
+                    };
+
+                    Event::TextEditingCandidates {
+                        timestamp: event.timestamp,
+                        window_id: Self::window_id_from_ll(event.windowID),
+                        candidates,
+                        selected: event.selected_candidate,
+                        horizontal: event.horizontal,
+                    }
+                }
 
                 EventType::MouseMotion => {
                     let event = raw.motion;
@@ -2386,6 +2442,7 @@ impl Event {
             | (Self::KeyUp { .. }, Self::KeyUp { .. })
             | (Self::TextEditing { .. }, Self::TextEditing { .. })
             | (Self::TextInput { .. }, Self::TextInput { .. })
+            | (Self::TextEditingCandidates { .. }, Self::TextEditingCandidates { .. })
             | (Self::MouseMotion { .. }, Self::MouseMotion { .. })
             | (Self::MouseButtonDown { .. }, Self::MouseButtonDown { .. })
             | (Self::MouseButtonUp { .. }, Self::MouseButtonUp { .. })
@@ -2453,6 +2510,7 @@ impl Event {
             Self::KeyUp { timestamp, .. } => timestamp,
             Self::TextEditing { timestamp, .. } => timestamp,
             Self::TextInput { timestamp, .. } => timestamp,
+            Self::TextEditingCandidates { timestamp, .. } => timestamp,
             Self::MouseMotion { timestamp, .. } => timestamp,
             Self::MouseButtonDown { timestamp, .. } => timestamp,
             Self::MouseButtonUp { timestamp, .. } => timestamp,
@@ -2528,6 +2586,7 @@ impl Event {
             Self::KeyUp { window_id, .. } => Some(*window_id),
             Self::TextEditing { window_id, .. } => Some(*window_id),
             Self::TextInput { window_id, .. } => Some(*window_id),
+            Self::TextEditingCandidates { window_id, .. } => Some(*window_id),
             Self::MouseMotion { window_id, .. } => Some(*window_id),
             Self::MouseButtonDown { window_id, .. } => Some(*window_id),
             Self::MouseButtonUp { window_id, .. } => Some(*window_id),
@@ -2544,6 +2603,34 @@ impl Event {
         }
     }
 
+    /// Returns the `display` field of the event if it's a display event.
+    ///
+    /// `DisplayEvent` itself only describes what happened; the display it happened to is
+    /// carried by `Event::Display`. On `DisplayEvent::ContentScaleChanged`, call
+    /// `display.get_content_scale()` on the returned display to fetch the new scale.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sdl3::event::{DisplayEvent, Event};
+    ///
+    /// let ev = Event::Quit { timestamp: 0 };
+    /// assert!(ev.get_display().is_none());
+    ///
+    /// # fn handle(ev: Event) {
+    /// if let Event::Display { display_event: DisplayEvent::ContentScaleChanged, .. } = ev {
+    ///     let display = ev.get_display().unwrap();
+    ///     let _scale = display.get_content_scale();
+    /// }
+    /// # }
+    /// ```
+    pub fn get_display(&self) -> Option<Display> {
+        match self {
+            Self::Display { display, .. } => Some(*display),
+            _ => None,
+        }
+    }
+
     /// Returns `true` if this is a window event.
     ///
     /// # Example
@@ -2611,6 +2698,43 @@ impl Event {
         matches!(self, Self::KeyDown { .. } | Self::KeyUp { .. })
     }
 
+    /// Returns `true` if this is a keyboard event generated by key repeat.
+    ///
+    /// The flag comes straight from SDL, which sets it on the repeated `KeyDown`
+    /// events sent while a key is held, following the OS auto-repeat settings.
+    pub fn is_repeat(&self) -> bool {
+        matches!(
+            self,
+            Self::KeyDown { repeat: true, .. } | Self::KeyUp { repeat: true, .. }
+        )
+    }
+
+    /// Returns `true` if this is a `KeyDown` event for a key that was just pressed,
+    /// rather than one repeated by the OS while the key is held.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sdl3::event::Event;
+    /// use sdl3::keyboard::Mod;
+    ///
+    /// let key_down = |repeat| Event::KeyDown {
+    ///     timestamp: 0,
+    ///     window_id: 0,
+    ///     keycode: None,
+    ///     scancode: None,
+    ///     keymod: Mod::empty(),
+    ///     repeat,
+    ///     which: 0,
+    ///     raw: 0,
+    /// };
+    /// assert!(key_down(false).is_fresh_keydown());
+    /// assert!(!key_down(true).is_fresh_keydown());
+    /// ```
+    pub fn is_fresh_keydown(&self) -> bool {
+        matches!(self, Self::KeyDown { repeat: false, .. })
+    }
+
     /// Returns `true` if this is a text event.
     ///
     /// # Example
@@ -2631,7 +2755,10 @@ impl Event {
     /// assert!(another_ev.is_text() == false); // Not a text event!
     /// ```
     pub fn is_text(&self) -> bool {
-        matches!(self, Self::TextEditing { .. } | Self::TextInput { .. })
+        matches!(
+            self,
+            Self::TextEditing { .. } | Self::TextInput { .. } | Self::TextEditingCandidates { .. }
+        )
     }
 
     /// Returns `true` if this is a mouse event.
@@ -2996,6 +3123,51 @@ impl crate::EventPump {
         unsafe { poll_event() }
     }
 
+    /// Removes and returns the first pending event whose type lies within
+    /// `min..=max`, leaving all other events in the queue.
+    ///
+    /// This lets one consumer drain, say, only gamepad events while keyboard
+    /// events stay queued for another. Unlike [`poll_event`](Self::poll_event),
+    /// this doesn't pump the event loop; call
+    /// [`pump_events`](Self::pump_events) first to gather new input.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl3::event::EventType;
+    ///
+    /// let sdl_context = sdl3::init().unwrap();
+    /// let mut event_pump = sdl_context.event_pump().unwrap();
+    ///
+    /// event_pump.pump_events();
+    /// while let Some(event) =
+    ///     event_pump.poll_in_range(EventType::MouseMotion, EventType::MouseWheel)
+    /// {
+    ///     println!("{event:?}");
+    /// }
+    /// ```
+    #[doc(alias = "SDL_PeepEvents")]
+    pub fn poll_in_range(&mut self, min: EventType, max: EventType) -> Option<Event> {
+        let mut raw = mem::MaybeUninit::uninit();
+        let result = unsafe {
+            sys::events::SDL_PeepEvents(
+                raw.as_mut_ptr(),
+                1,
+                sys::events::SDL_GETEVENT,
+                min.into(),
+                max.into(),
+            )
+        };
+
+        if result < 0 {
+            // The only error possible is "Couldn't lock event queue"
+            panic!("{}", get_error());
+        } else if result == 0 {
+            None
+        } else {
+            Some(Event::from_ll(unsafe { raw.assume_init() }))
+        }
+    }
+
     /// Returns a polling iterator that calls `poll_event()`.
     /// The iterator will terminate once there are no more pending events.
     ///
@@ -3569,6 +3741,30 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_get_display() {
+        let display = Display::from_ll(sys::video::SDL_DisplayID(7));
+        let kinds = [
+            DisplayEvent::Orientation(Orientation::Portrait),
+            DisplayEvent::Added,
+            DisplayEvent::Removed,
+            DisplayEvent::Moved,
+            DisplayEvent::DesktopModeChanged,
+            DisplayEvent::CurrentModeChanged,
+            DisplayEvent::ContentScaleChanged,
+        ];
+        for display_event in kinds {
+            let e = Event::Display {
+                timestamp: 0,
+                display,
+                display_event,
+            };
+            let e2 = Event::from_ll(e.to_ll().unwrap());
+            assert_eq!(e2.get_display(), Some(display));
+        }
+        assert_eq!(Event::Quit { timestamp: 0 }.get_display(), None);
+    }
+
     #[test]
     fn test_from_ll_keymod_keydown_unknown_bits() {
         let mut raw_event = Event::KeyDown {
@@ -3622,4 +3818,103 @@ mod test {
             panic!()
         }
     }
+
+    #[test]
+    fn test_fresh_keydown_ignores_repeat() {
+        let key = |down: bool, repeat: bool| {
+            let (keycode, scancode) = (Some(Keycode::Space), Some(Scancode::Space));
+            if down {
+                Event::KeyDown {
+                    timestamp: 0,
+                    window_id: 1,
+                    keycode,
+                    scancode,
+                    keymod: Mod::empty(),
+                    repeat,
+                    which: 0,
+                    raw: 0,
+                }
+            } else {
+                Event::KeyUp {
+                    timestamp: 0,
+                    window_id: 1,
+                    keycode,
+                    scancode,
+                    keymod: Mod::empty(),
+                    repeat,
+                    which: 0,
+                    raw: 0,
+                }
+            }
+        };
+
+        // The flag survives a round trip through SDL's representation.
+        let pressed = Event::from_ll(key(true, false).to_ll().unwrap());
+        let repeated = Event::from_ll(key(true, true).to_ll().unwrap());
+        assert!(pressed.is_fresh_keydown());
+        assert!(!pressed.is_repeat());
+        assert!(!repeated.is_fresh_keydown());
+        assert!(repeated.is_repeat());
+
+        assert!(!key(false, false).is_fresh_keydown());
+        assert!(!Event::Quit { timestamp: 0 }.is_fresh_keydown());
+        assert!(!Event::Quit { timestamp: 0 }.is_repeat());
+    }
+
+    #[test]
+    fn test_from_ll_text_editing_candidates() {
+        let words = [
+            c"\u{4f60}\u{597d}",
+            c"\u{62df}\u{597d}",
+            c"\u{5c3c}\u{597d}",
+        ];
+        let pointers = words.map(|w| w.as_ptr());
+
+        let mut raw: sys::events::SDL_Event = unsafe { std::mem::zeroed() };
+        unsafe {
+            raw.edit_candidates.r#type = sys::events::SDL_EVENT_TEXT_EDITING_CANDIDATES;
+            raw.edit_candidates.timestamp = 42;
+            raw.edit_candidates.windowID = sys::video::SDL_WindowID(3);
+            raw.edit_candidates.candidates = pointers.as_ptr();
+            raw.edit_candidates.num_candidates = pointers.len() as i32;
+            raw.edit_candidates.selected_candidate = 1;
+            raw.edit_candidates.horizontal = true;
+        }
+
+        let e = Event::from_ll(raw);
+        assert_eq!(
+            e,
+            Event::TextEditingCandidates {
+                timestamp: 42,
+                window_id: 3,
+                candidates: vec![
+                    "\u{4f60}\u{597d}".to_owned(),
+                    "\u{62df}\u{597d}".to_owned(),
+                    "\u{5c3c}\u{597d}".to_owned(),
+                ],
+                selected: 1,
+                horizontal: true,
+            }
+        );
+        assert!(e.is_text());
+        assert_eq!(e.get_window_id(), Some(3));
+
+        // SDL sends an empty list when the popup should be hidden.
+        unsafe {
+            raw.edit_candidates.candidates = std::ptr::null();
+            raw.edit_candidates.num_candidates = 0;
+            raw.edit_candidates.selected_candidate = -1;
+        }
+        match Event::from_ll(raw) {
+            Event::TextEditingCandidates {
+                candidates,
+                selected,
+                ..
+            } => {
+                assert!(candidates.is_empty());
+                assert_eq!(selected, -1);
+            }
+            e => panic!("unexpected event {e:?}"),
+        }
+    }
 }
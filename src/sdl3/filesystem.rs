@@ -68,6 +68,7 @@ macro_rules! cstring_path {
     };
 }
 
+/// Copy the file at `old_path` to `new_path`, overwriting it if it exists.
 #[doc(alias = "SDL_CopyFile")]
 pub fn copy_file(
     old_path: impl AsRef<Path>,
@@ -83,6 +84,7 @@ pub fn copy_file(
     Ok(())
 }
 
+/// Create `path`, along with any missing parent directories.
 #[doc(alias = "SDL_CreateDirectory")]
 pub fn create_directory(path: impl AsRef<Path>) -> Result<(), FileSystemError> {
     path_cstring!(path);
@@ -98,12 +100,15 @@ pub use sys::filesystem::SDL_EnumerationResult as EnumerationResult;
 
 pub type EnumerateCallback = fn(&Path, &Path) -> EnumerationResult;
 
-unsafe extern "C" fn c_enumerate_directory(
+unsafe extern "C" fn c_enumerate_directory<F>(
     userdata: *mut c_void,
     dirname: *const c_char,
     fname: *const c_char,
-) -> EnumerationResult {
-    let callback: EnumerateCallback = std::mem::transmute(userdata);
+) -> EnumerationResult
+where
+    F: FnMut(&Path, &Path) -> EnumerationResult,
+{
+    let callback = &mut *(userdata as *mut F);
 
     cstring_path!(dirname, return EnumerationResult::FAILURE);
     cstring_path!(fname, return EnumerationResult::FAILURE);
@@ -111,17 +116,37 @@ unsafe extern "C" fn c_enumerate_directory(
     callback(dirname, fname)
 }
 
+/// Call `callback` with the directory name and file name of every entry in
+/// `path`, without recursing.
+///
+/// The callback decides whether to go on with `EnumerationResult::CONTINUE`,
+/// stop early with `SUCCESS`, or abort with `FAILURE`, which makes this
+/// function return an error.
+///
+/// ```no_run
+/// use sdl3::filesystem::{enumerate_directory, EnumerationResult};
+///
+/// let mut names = Vec::new();
+/// enumerate_directory("assets", |_dir, name| {
+///     names.push(name.to_owned());
+///     EnumerationResult::CONTINUE
+/// })
+/// .unwrap();
+/// ```
 #[doc(alias = "SDL_EnumerateDirectory")]
-pub fn enumerate_directory(
+pub fn enumerate_directory<F>(
     path: impl AsRef<Path>,
-    callback: EnumerateCallback,
-) -> Result<(), FileSystemError> {
+    mut callback: F,
+) -> Result<(), FileSystemError>
+where
+    F: FnMut(&Path, &Path) -> EnumerationResult,
+{
     path_cstring!(path);
     unsafe {
         if !sys::filesystem::SDL_EnumerateDirectory(
             path.as_ptr(),
-            Some(c_enumerate_directory),
-            callback as *mut c_void,
+            Some(c_enumerate_directory::<F>),
+            &mut callback as *mut F as *mut c_void,
         ) {
             return Err(FileSystemError::SdlError(get_error()));
         }
@@ -129,6 +154,10 @@ pub fn enumerate_directory(
     Ok(())
 }
 
+/// Return the directory the application was run from, which is usually
+/// where its assets are installed.
+///
+/// The returned path ends with a path separator.
 #[doc(alias = "SDL_GetBasePath")]
 pub fn get_base_path() -> Result<&'static Path, FileSystemError> {
     unsafe {
@@ -161,28 +190,31 @@ pub fn get_current_directory() -> Result<PathBuf, FileSystemError> {
 
 pub use sys::filesystem::SDL_PathType as PathType;
 
+/// Information about a file system entry, see [`get_path_info`].
 pub struct PathInfo {
     internal: SDL_PathInfo,
 }
 
 impl PathInfo {
-    fn path_type(&self) -> PathType {
+    /// Whether the entry is a file, a directory or something else.
+    pub fn path_type(&self) -> PathType {
         self.internal.r#type as PathType
     }
 
-    fn size(&self) -> usize {
+    /// Size of the entry in bytes.
+    pub fn size(&self) -> usize {
         self.internal.size as usize
     }
 
-    fn create_time(&self) -> SystemTime {
+    pub fn create_time(&self) -> SystemTime {
         UNIX_EPOCH + Duration::from_nanos(self.internal.create_time as u64)
     }
 
-    fn modify_time(&self) -> SystemTime {
+    pub fn modify_time(&self) -> SystemTime {
         UNIX_EPOCH + Duration::from_nanos(self.internal.modify_time as u64)
     }
 
-    fn access_time(&self) -> SystemTime {
+    pub fn access_time(&self) -> SystemTime {
         UNIX_EPOCH + Duration::from_nanos(self.internal.access_time as u64)
     }
 }
@@ -207,6 +239,7 @@ impl fmt::Debug for PathInfo {
     }
 }
 
+/// Get the type, size and timestamps of `path`.
 #[doc(alias = "SDL_GetPathInfo")]
 pub fn get_path_info(path: impl AsRef<Path>) -> Result<PathInfo, FileSystemError> {
     let mut info = SDL_PathInfo {
@@ -260,6 +293,9 @@ impl error::Error for PrefPathError {
 
 /// Return the preferred directory for the application to write files on this
 /// system, based on the given organization and application name.
+///
+/// The directory is created if it doesn't exist yet, and the returned path
+/// ends with a path separator.
 #[doc(alias = "SDL_GetPrefPath")]
 pub fn get_pref_path(org_name: &str, app_name: &str) -> Result<PathBuf, PrefPathError> {
     let org = match CString::new(org_name) {
@@ -271,20 +307,21 @@ pub fn get_pref_path(org_name: &str, app_name: &str) -> Result<PathBuf, PrefPath
         Err(err) => return Err(PrefPathError::InvalidApplicationName(err)),
     };
 
-    let path = unsafe {
+    unsafe {
         let buf = sys::filesystem::SDL_GetPrefPath(
             org.as_ptr() as *const c_char,
             app.as_ptr() as *const c_char,
         );
-        let path = PathBuf::from(CStr::from_ptr(buf).to_str().unwrap());
+        if buf.is_null() {
+            return Err(PrefPathError::SdlError(get_error()));
+        }
+
+        let path = CStr::from_ptr(buf)
+            .to_str()
+            .map(PathBuf::from)
+            .map_err(|_| PrefPathError::SdlError(Error("pref path is not valid UTF-8".to_owned())));
         sys::stdinc::SDL_free(buf as *mut c_void);
         path
-    };
-
-    if path.as_os_str().is_empty() {
-        Err(PrefPathError::SdlError(get_error()))
-    } else {
-        Ok(path)
     }
 }
 
@@ -383,6 +420,10 @@ impl Drop for GlobResults<'_> {
     }
 }
 
+/// List the entries of `path`, recursively, whose path relative to `path`
+/// matches `pattern`.
+///
+/// `pattern` supports `*` and `?` wildcards; `None` matches everything.
 #[doc(alias = "SDL_GlobDirectory")]
 pub fn glob_directory(
     path: impl AsRef<Path>,
@@ -416,6 +457,7 @@ pub fn glob_directory(
     Ok(results)
 }
 
+/// Remove the file or empty directory at `path`.
 #[doc(alias = "SDL_RemovePath")]
 pub fn remove_path(path: impl AsRef<Path>) -> Result<(), FileSystemError> {
     path_cstring!(path);
@@ -427,6 +469,7 @@ pub fn remove_path(path: impl AsRef<Path>) -> Result<(), FileSystemError> {
     Ok(())
 }
 
+/// Move `old_path` to `new_path`, replacing it if it exists.
 #[doc(alias = "SDL_RenamePath")]
 pub fn rename_path(
     old_path: impl AsRef<Path>,
@@ -6,6 +6,7 @@ use std::fmt;
 use std::io;
 use std::mem::transmute;
 use std::path::Path;
+use std::time::Duration;
 
 #[cfg(feature = "hidapi")]
 use crate::sensor::SensorType;
@@ -17,6 +18,7 @@ use crate::get_error;
 use crate::guid::Guid;
 use crate::iostream::IOStream;
 use crate::joystick::{ConnectionState, JoystickId, PowerInfo, PowerLevel};
+use crate::pixels::Color;
 use crate::sys;
 use crate::Error;
 use crate::GamepadSubsystem;
@@ -333,6 +335,23 @@ impl GamepadSubsystem {
         }
     }
 
+    /// Load controller input mappings, such as a `gamecontrollerdb.txt`
+    /// database, from a borrowed SDL [`IOStream`].
+    ///
+    /// Unlike [`GamepadSubsystem::load_mappings_from_rw`], the stream is
+    /// left open so it can be rewound or reused afterwards. Returns the
+    /// number of mappings added.
+    #[doc(alias = "SDL_AddGamepadMappingsFromIO")]
+    pub fn add_mappings_from_io(&self, stream: &mut IOStream<'_>) -> Result<i32, AddMappingError> {
+        use self::AddMappingError::*;
+
+        let result = unsafe { sys::gamepad::SDL_AddGamepadMappingsFromIO(stream.raw(), false) };
+        match result {
+            -1 => Err(SdlError(get_error())),
+            _ => Ok(result),
+        }
+    }
+
     #[doc(alias = "SDL_GetGamepadMappingForGUID")]
     pub fn mapping_for_guid(&self, guid: Guid) -> Result<String, Error> {
         let c_str = unsafe { sys::gamepad::SDL_GetGamepadMappingForGUID(guid.raw()) };
@@ -744,6 +763,19 @@ pub enum MappingStatus {
     Updated = 0,
 }
 
+/// The state of one finger on a gamepad touchpad, see [`Gamepad::touchpad_finger`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TouchpadFinger {
+    /// Whether the finger is touching the touchpad
+    pub down: bool,
+    /// Normalized in the range 0...1 with 0 being on the left
+    pub x: f32,
+    /// Normalized in the range 0...1 with 0 being at the top
+    pub y: f32,
+    /// Normalized in the range 0...1
+    pub pressure: f32,
+}
+
 /// Wrapper around the `SDL_Gamepad` object
 pub struct Gamepad {
     subsystem: GamepadSubsystem,
@@ -772,7 +804,12 @@ impl Gamepad {
     }
 
     /// Return the type of an opened gamepad.
-    #[doc(alias = "SDL_GetGamepadType")]
+    ///
+    /// Use this to pick controller-specific button glyphs, or see
+    /// [`Gamepad::button_label_for_gamepad_type`] for the face buttons. Gamepads SDL
+    /// doesn't recognize are reported as [`GamepadType::Standard`] or
+    /// [`GamepadType::Unknown`].
+    #[doc(alias = "SDL_GetGamepadType", alias = "gamepad_type")]
     pub fn r#type(&self) -> GamepadType {
         let raw_type = unsafe { sys::gamepad::SDL_GetGamepadType(self.raw) };
         GamepadType::from_ll(raw_type)
@@ -785,7 +822,9 @@ impl Gamepad {
         GamepadType::from_ll(raw_type)
     }
 
-    /// Return the player index of an opened gamepad.
+    /// Return the player index of an opened gamepad, or `None` if it has none.
+    ///
+    /// On XInput controllers this matches the lit player LED.
     #[doc(alias = "SDL_GetGamepadPlayerIndex")]
     pub fn player_index(&self) -> Option<u16> {
         let c_int = unsafe { sys::gamepad::SDL_GetGamepadPlayerIndex(self.raw) };
@@ -926,6 +965,9 @@ impl Gamepad {
 
     /// Return true if the controller has been opened and currently
     /// connected.
+    ///
+    /// This turns false as soon as the device is unplugged, so it can be polled to
+    /// detect a hot-unplug without waiting for the removal event.
     #[doc(alias = "SDL_GamepadConnected")]
     pub fn connected(&self) -> bool {
         unsafe { sys::gamepad::SDL_GamepadConnected(self.raw) }
@@ -1018,17 +1060,44 @@ impl Gamepad {
         unsafe { sys::gamepad::SDL_GetNumGamepadTouchpadFingers(self.raw, touchpad as i32) as u16 }
     }
 
+    /// Return the current state of a finger on a touchpad, or `None` if the touchpad
+    /// or finger index is out of range.
+    ///
+    /// Fingers that aren't touching the touchpad are reported with `down` set to
+    /// false. This polls the same state reported by the `ControllerTouchpad*` events.
+    #[doc(alias = "SDL_GetGamepadTouchpadFinger")]
+    pub fn touchpad_finger(&self, touchpad: u16, finger: u16) -> Option<TouchpadFinger> {
+        let mut down = false;
+        let mut x = 0.0;
+        let mut y = 0.0;
+        let mut pressure = 0.0;
+        let ok = unsafe {
+            sys::gamepad::SDL_GetGamepadTouchpadFinger(
+                self.raw,
+                touchpad as i32,
+                finger as i32,
+                &mut down,
+                &mut x,
+                &mut y,
+                &mut pressure,
+            )
+        };
+        ok.then_some(TouchpadFinger {
+            down,
+            x,
+            y,
+            pressure,
+        })
+    }
+
     /// Set the rumble motors to their specified intensities, if supported.
     /// Automatically resets back to zero after `duration_ms` milliseconds have passed.
     ///
+    /// Same as [`Gamepad::rumble`], with the duration in milliseconds.
+    ///
     /// # Notes
     ///
     /// The value range for the intensities is 0 to 0xFFFF.
-    ///
-    /// Do *not* use `std::u32::MAX` or similar for `duration_ms` if you want
-    /// the rumble effect to keep playing for a long time, as this results in
-    /// the effect ending immediately after starting due to an overflow.
-    /// Use some smaller, "huge enough" number instead.
     #[doc(alias = "SDL_RumbleGamepad")]
     pub fn set_rumble(
         &mut self,
@@ -1036,43 +1105,81 @@ impl Gamepad {
         high_frequency_rumble: u16,
         duration_ms: u32,
     ) -> Result<(), IntegerOrSdlError> {
+        self.rumble(
+            low_frequency_rumble,
+            high_frequency_rumble,
+            Duration::from_millis(duration_ms.into()),
+        )
+        .map_err(IntegerOrSdlError::SdlError)
+    }
+
+    /// Start a rumble effect in the game controller's triggers.
+    ///
+    /// Same as [`Gamepad::rumble_triggers`], with the duration in milliseconds.
+    #[doc(alias = "SDL_RumbleGamepadTriggers")]
+    pub fn set_rumble_triggers(
+        &mut self,
+        left_rumble: u16,
+        right_rumble: u16,
+        duration_ms: u32,
+    ) -> Result<(), IntegerOrSdlError> {
+        self.rumble_triggers(
+            left_rumble,
+            right_rumble,
+            Duration::from_millis(duration_ms.into()),
+        )
+        .map_err(IntegerOrSdlError::SdlError)
+    }
+
+    /// Set the rumble motors to their specified intensities for `duration`,
+    /// if supported.
+    ///
+    /// Durations longer than `u32::MAX` milliseconds are clamped, and SDL
+    /// itself may cap how long a single effect lasts, so long effects should
+    /// be renewed periodically. Passing a zero duration with zero intensities
+    /// stops any rumble in progress.
+    #[doc(alias = "SDL_RumbleGamepad")]
+    pub fn rumble(
+        &mut self,
+        low_freq: u16,
+        high_freq: u16,
+        duration: Duration,
+    ) -> Result<(), Error> {
         let result = unsafe {
-            sys::gamepad::SDL_RumbleGamepad(
-                self.raw,
-                low_frequency_rumble,
-                high_frequency_rumble,
-                duration_ms,
-            )
+            sys::gamepad::SDL_RumbleGamepad(self.raw, low_freq, high_freq, duration_to_ms(duration))
         };
 
-        if !result {
-            Err(IntegerOrSdlError::SdlError(get_error()))
-        } else {
+        if result {
             Ok(())
+        } else {
+            Err(get_error())
         }
     }
 
-    /// Start a rumble effect in the game controller's triggers.
+    /// Start a rumble effect in the game controller's triggers for `duration`,
+    /// if supported.
+    ///
+    /// Durations are clamped the same way as in [`Gamepad::rumble`].
     #[doc(alias = "SDL_RumbleGamepadTriggers")]
-    pub fn set_rumble_triggers(
+    pub fn rumble_triggers(
         &mut self,
         left_rumble: u16,
         right_rumble: u16,
-        duration_ms: u32,
-    ) -> Result<(), IntegerOrSdlError> {
+        duration: Duration,
+    ) -> Result<(), Error> {
         let result = unsafe {
             sys::gamepad::SDL_RumbleGamepadTriggers(
                 self.raw,
                 left_rumble,
                 right_rumble,
-                duration_ms,
+                duration_to_ms(duration),
             )
         };
 
-        if !result {
-            Err(IntegerOrSdlError::SdlError(get_error()))
-        } else {
+        if result {
             Ok(())
+        } else {
+            Err(get_error())
         }
     }
 
@@ -1109,15 +1216,20 @@ impl Gamepad {
         )
     }
 
-    /// Update a game controller's LED color.
+    /// Update a game controller's LED color, such as the DualShock 4 or
+    /// DualSense light bar.
+    ///
+    /// The alpha component of `color` is ignored. Returns an error if the
+    /// controller has no LED.
     #[doc(alias = "SDL_SetGamepadLED")]
-    pub fn set_led(&mut self, red: u8, green: u8, blue: u8) -> Result<(), IntegerOrSdlError> {
-        let result = unsafe { sys::gamepad::SDL_SetGamepadLED(self.raw, red, green, blue) };
+    pub fn set_led(&mut self, color: Color) -> Result<(), Error> {
+        let result =
+            unsafe { sys::gamepad::SDL_SetGamepadLED(self.raw, color.r, color.g, color.b) };
 
         if result {
             Ok(())
         } else {
-            Err(IntegerOrSdlError::SdlError(get_error()))
+            Err(get_error())
         }
     }
 
@@ -1152,24 +1264,38 @@ impl Gamepad {
         unsafe { sys::gamepad::SDL_GamepadSensorEnabled(self.raw, sensor_type.into()) }
     }
 
+    /// Enable or disable data reporting for a sensor on this gamepad.
+    ///
+    /// Once enabled, the latest readings can be polled with
+    /// [`Gamepad::get_sensor_data`] in addition to arriving as
+    /// `Event::ControllerSensorUpdated`.
     #[doc(alias = "SDL_SetGamepadSensorEnabled")]
-    pub fn sensor_set_enabled(
-        &self,
-        sensor_type: crate::sensor::SensorType,
-        enabled: bool,
-    ) -> Result<(), IntegerOrSdlError> {
+    pub fn set_sensor_enabled(&self, sensor: SensorType, enabled: bool) -> Result<(), Error> {
+        let result =
+            unsafe { sys::gamepad::SDL_SetGamepadSensorEnabled(self.raw, sensor.into(), enabled) };
+
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Read the current state of an enabled sensor into `out`.
+    ///
+    /// Both the gyroscope and the accelerometer report 3 values, one for
+    /// each axis.
+    #[doc(alias = "SDL_GetGamepadSensorData")]
+    pub fn get_sensor_data(&self, sensor: SensorType, out: &mut [f32]) -> Result<(), Error> {
+        let len = i32::try_from(out.len()).unwrap_or(i32::MAX);
         let result = unsafe {
-            sys::gamepad::SDL_SetGamepadSensorEnabled(
-                self.raw,
-                sensor_type.into(),
-                if enabled { true } else { false },
-            )
+            sys::gamepad::SDL_GetGamepadSensorData(self.raw, sensor.into(), out.as_mut_ptr(), len)
         };
 
-        if !result {
-            Err(IntegerOrSdlError::SdlError(get_error()))
-        } else {
+        if result {
             Ok(())
+        } else {
+            Err(get_error())
         }
     }
 
@@ -1179,30 +1305,24 @@ impl Gamepad {
         unsafe { sys::gamepad::SDL_GetGamepadSensorDataRate(self.raw, sensor_type.into()) }
     }
 
-    /// Get data from a sensor.
-    ///
-    /// The number of data points depends on the sensor. Both Gyroscope and
-    /// Accelerometer return 3 values, one for each axis.
-    #[doc(alias = "SDL_GetGamepadSensorData")]
+    #[deprecated(since = "0.18.5", note = "Use set_sensor_enabled instead.")]
+    pub fn sensor_set_enabled(
+        &self,
+        sensor_type: SensorType,
+        enabled: bool,
+    ) -> Result<(), IntegerOrSdlError> {
+        self.set_sensor_enabled(sensor_type, enabled)
+            .map_err(IntegerOrSdlError::SdlError)
+    }
+
+    #[deprecated(since = "0.18.5", note = "Use get_sensor_data instead.")]
     pub fn sensor_get_data(
         &self,
         sensor_type: SensorType,
         data: &mut [f32],
     ) -> Result<(), IntegerOrSdlError> {
-        let result = unsafe {
-            sys::gamepad::SDL_GetGamepadSensorData(
-                self.raw,
-                sensor_type.into(),
-                data.as_mut_ptr(),
-                data.len().try_into().unwrap(),
-            )
-        };
-
-        if !result {
-            Err(IntegerOrSdlError::SdlError(get_error()))
-        } else {
-            Ok(())
-        }
+        self.get_sensor_data(sensor_type, data)
+            .map_err(IntegerOrSdlError::SdlError)
     }
 }
 
@@ -1213,6 +1333,11 @@ impl Drop for Gamepad {
     }
 }
 
+/// Convert `duration` to whole milliseconds, saturating at `u32::MAX`.
+fn duration_to_ms(duration: Duration) -> u32 {
+    u32::try_from(duration.as_millis()).unwrap_or(u32::MAX)
+}
+
 /// Convert C string `c_str` to a String. Return an empty string if
 /// `c_str` is NULL.
 fn c_str_to_string(c_str: *const c_char) -> String {
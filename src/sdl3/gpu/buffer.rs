@@ -6,7 +6,7 @@ use crate::{
 use std::sync::Arc;
 use sys::gpu::{
     SDL_CreateGPUBuffer, SDL_CreateGPUTransferBuffer, SDL_GPUBuffer, SDL_GPUBufferBinding,
-    SDL_GPUBufferCreateInfo, SDL_GPUBufferRegion, SDL_GPUTransferBuffer,
+    SDL_GPUBufferCreateInfo, SDL_GPUBufferLocation, SDL_GPUBufferRegion, SDL_GPUTransferBuffer,
     SDL_GPUTransferBufferCreateInfo, SDL_GPUTransferBufferLocation, SDL_GPUVertexBufferDescription,
     SDL_GPUVertexInputRate, SDL_MapGPUTransferBuffer, SDL_ReleaseGPUBuffer,
     SDL_ReleaseGPUTransferBuffer, SDL_UnmapGPUTransferBuffer,
@@ -33,6 +33,26 @@ impl BufferBinding {
     }
 }
 
+#[derive(Default)]
+pub struct BufferLocation {
+    pub(super) inner: SDL_GPUBufferLocation,
+}
+impl BufferLocation {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_buffer(mut self, buffer: &Buffer) -> Self {
+        self.inner.buffer = buffer.raw();
+        self
+    }
+
+    pub fn with_offset(mut self, offset: u32) -> Self {
+        self.inner.offset = offset;
+        self
+    }
+}
+
 #[derive(Default)]
 pub struct TransferBufferLocation {
     pub(super) inner: SDL_GPUTransferBufferLocation,
@@ -1,27 +1,36 @@
 use crate::{
     get_error,
     gpu::{
-        BufferBuilder, ColorTargetInfo, CommandBuffer, CopyPass, DepthStencilTargetInfo,
-        GraphicsPipelineBuilder, PresentMode, RenderPass, Sampler, SamplerCreateInfo,
-        ShaderBuilder, ShaderFormat, SwapchainComposition, Texture, TextureCreateInfo,
-        TextureFormat, TransferBufferBuilder,
+        Buffer, BufferBuilder, BufferRegion, ColorTargetInfo, CommandBuffer, CopyPass,
+        DepthStencilTargetInfo, GraphicsPipelineBuilder, PresentMode, RenderPass, Sampler,
+        SamplerCreateInfo, ShaderBuilder, ShaderFormat, SwapchainComposition, Texture,
+        TextureCreateInfo, TextureFormat, TransferBufferBuilder, TransferBufferLocation,
+        TransferBufferUsage,
     },
     properties::Properties,
     sys,
     video::Window,
     Error,
 };
-use std::sync::{Arc, Weak};
+use std::{
+    collections::HashMap,
+    ops::Range,
+    sync::{Arc, Mutex, Weak},
+};
 use sys::gpu::{
     SDL_BeginGPUComputePass, SDL_BeginGPUCopyPass, SDL_BeginGPURenderPass, SDL_CreateGPUDevice,
     SDL_CreateGPUDeviceWithProperties, SDL_CreateGPUSampler, SDL_CreateGPUTexture,
     SDL_DestroyGPUDevice, SDL_GPUColorTargetInfo, SDL_GPUDepthStencilTargetInfo, SDL_GPUDevice,
-    SDL_GPUViewport, SDL_GetGPUSwapchainTextureFormat, SDL_SetGPUViewport,
+    SDL_GPUViewport, SDL_GetGPUSwapchainTextureFormat, SDL_ReleaseGPUGraphicsPipeline,
+    SDL_ReleaseGPUShader,
 };
 
 use super::{
     pass::Fence,
-    pipeline::{StorageBufferReadWriteBinding, StorageTextureReadWriteBinding},
+    pipeline::{
+        CachedGraphicsPipeline, GraphicsPipelineKey, StorageBufferReadWriteBinding,
+        StorageTextureReadWriteBinding,
+    },
     ComputePass, ComputePipelineBuilder,
 };
 
@@ -50,16 +59,40 @@ impl Viewport {
 }
 
 /// Manages the raw `SDL_GPUDevice` pointer and releases it on drop
-pub(super) struct DeviceContainer(*mut SDL_GPUDevice);
+pub(super) struct DeviceContainer {
+    raw: *mut SDL_GPUDevice,
+    /// Pipelines created through [`Device::create_graphics_pipeline_cached`], keyed
+    /// by their create-info.
+    graphics_pipelines: Mutex<HashMap<GraphicsPipelineKey, CachedGraphicsPipeline>>,
+}
 impl DeviceContainer {
+    fn new(raw: *mut SDL_GPUDevice) -> Self {
+        Self {
+            raw,
+            graphics_pipelines: Mutex::default(),
+        }
+    }
+
     pub(super) fn raw(&self) -> *mut SDL_GPUDevice {
-        self.0
+        self.raw
     }
 }
 impl Drop for DeviceContainer {
     #[doc(alias = "SDL_DestroyGPUDevice")]
     fn drop(&mut self) {
-        unsafe { SDL_DestroyGPUDevice(self.0) }
+        // Cached pipelines and their shaders can't upgrade their weak device
+        // handle anymore, so they are released here, before the device goes away.
+        let cache = self
+            .graphics_pipelines
+            .get_mut()
+            .unwrap_or_else(|e| e.into_inner());
+        for (_, entry) in cache.drain() {
+            unsafe { SDL_ReleaseGPUGraphicsPipeline(self.raw, entry.pipeline.raw()) }
+            for shader in entry.shaders {
+                unsafe { SDL_ReleaseGPUShader(self.raw, shader.raw()) }
+            }
+        }
+        unsafe { SDL_DestroyGPUDevice(self.raw) }
     }
 }
 
@@ -75,13 +108,19 @@ pub struct Device {
 impl Device {
     #[inline]
     pub fn raw(&self) -> *mut SDL_GPUDevice {
-        self.inner.0
+        self.inner.raw
     }
 
     pub(super) fn weak(&self) -> WeakDevice {
         Arc::downgrade(&self.inner)
     }
 
+    pub(super) fn graphics_pipeline_cache(
+        &self,
+    ) -> &Mutex<HashMap<GraphicsPipelineKey, CachedGraphicsPipeline>> {
+        &self.inner.graphics_pipelines
+    }
+
     #[doc(alias = "SDL_CreateGPUDevice")]
     pub fn new(flags: ShaderFormat, debug_mode: bool) -> Result<Self, Error> {
         let raw_device = unsafe { SDL_CreateGPUDevice(flags.0, debug_mode, std::ptr::null()) };
@@ -89,7 +128,7 @@ impl Device {
             Err(get_error())
         } else {
             Ok(Self {
-                inner: Arc::new(DeviceContainer(raw_device)),
+                inner: Arc::new(DeviceContainer::new(raw_device)),
                 subsystem: None,
             })
         }
@@ -102,27 +141,50 @@ impl Device {
             Err(get_error())
         } else {
             Ok(Self {
-                inner: Arc::new(DeviceContainer(raw_device)),
+                inner: Arc::new(DeviceContainer::new(raw_device)),
                 subsystem: None,
             })
         }
     }
 
+    /// Claims `window` for this device and returns the device, keeping the video
+    /// subsystem alive for as long as the device is.
+    ///
+    /// See [`Device::claim_window`].
     #[doc(alias = "SDL_ClaimWindowForGPUDevice")]
     pub fn with_window(mut self, window: &crate::video::Window) -> Result<Self, Error> {
         self.subsystem = Some(window.subsystem().clone());
+        self.claim_window(window)?;
+        Ok(self)
+    }
 
-        let p = unsafe { sys::gpu::SDL_ClaimWindowForGPUDevice(self.inner.0, window.raw()) };
-        if p {
-            Ok(self)
+    /// Claims `window` for this device, creating its swapchain.
+    ///
+    /// A window must be claimed before swapchain textures can be acquired for it;
+    /// acquiring from an unclaimed window returns an error. A window can only be
+    /// claimed by one device at a time.
+    #[doc(alias = "SDL_ClaimWindowForGPUDevice")]
+    pub fn claim_window(&self, window: &Window) -> Result<(), Error> {
+        let ok = unsafe { sys::gpu::SDL_ClaimWindowForGPUDevice(self.raw(), window.raw()) };
+        if ok {
+            Ok(())
         } else {
             Err(get_error())
         }
     }
 
+    /// Releases a window previously claimed with [`Device::claim_window`] or
+    /// [`Device::with_window`], destroying its swapchain.
+    ///
+    /// Does nothing if the window was not claimed by this device.
+    #[doc(alias = "SDL_ReleaseWindowFromGPUDevice")]
+    pub fn release_window(&self, window: &Window) {
+        unsafe { sys::gpu::SDL_ReleaseWindowFromGPUDevice(self.raw(), window.raw()) }
+    }
+
     #[doc(alias = "SDL_AcquireGPUCommandBuffer")]
     pub fn acquire_command_buffer(&self) -> Result<CommandBuffer, Error> {
-        let raw_buffer = unsafe { sys::gpu::SDL_AcquireGPUCommandBuffer(self.inner.0) };
+        let raw_buffer = unsafe { sys::gpu::SDL_AcquireGPUCommandBuffer(self.inner.raw) };
         if raw_buffer.is_null() {
             Err(get_error())
         } else {
@@ -172,13 +234,14 @@ impl Device {
         }
     }
 
+    /// Same as [`RenderPass::set_viewport`].
     #[doc(alias = "SDL_SetGPUViewport")]
     pub fn set_viewport(&self, render_pass: &RenderPass, viewport: Viewport) {
-        unsafe { SDL_SetGPUViewport(render_pass.inner, viewport.raw()) }
+        render_pass.set_viewport(&viewport)
     }
 
     pub fn get_swapchain_texture_format(&self, w: &crate::video::Window) -> TextureFormat {
-        unsafe { std::mem::transmute(SDL_GetGPUSwapchainTextureFormat(self.inner.0, w.raw()).0) }
+        unsafe { std::mem::transmute(SDL_GetGPUSwapchainTextureFormat(self.inner.raw, w.raw()).0) }
     }
 
     // You cannot begin another render pass, or begin a compute pass or copy pass until you have ended the render pass.
@@ -264,10 +327,69 @@ impl Device {
         GraphicsPipelineBuilder::new(self)
     }
 
+    /// Like [`Device::create_graphics_pipeline`], but building returns an existing
+    /// pipeline if one was already built from an identical create-info.
+    ///
+    /// Cached pipelines live as long as the device, and keep the shaders they were
+    /// built from alive just as long.
+    pub fn create_graphics_pipeline_cached<'a>(&'a self) -> GraphicsPipelineBuilder<'a> {
+        GraphicsPipelineBuilder::new_cached(self)
+    }
+
     pub fn create_compute_pipeline<'a>(&'a self) -> ComputePipelineBuilder<'a> {
         ComputePipelineBuilder::new(self)
     }
 
+    /// Reads `range` (in bytes) of `buffer` back to the CPU.
+    ///
+    /// This records a copy into a temporary download transfer buffer, submits it and
+    /// blocks until the GPU has finished, so any work previously submitted that
+    /// writes to `buffer`, such as a compute dispatch, is visible in the result.
+    ///
+    /// Returns an error if `range` is reversed or extends past the end of the buffer.
+    #[doc(alias = "SDL_DownloadFromGPUBuffer")]
+    pub fn download_buffer(&self, buffer: &Buffer, range: Range<u32>) -> Result<Vec<u8>, Error> {
+        if range.start > range.end || range.end > buffer.len() {
+            return Err(Error(format!(
+                "download range {}..{} is out of bounds for a buffer of {} bytes",
+                range.start,
+                range.end,
+                buffer.len()
+            )));
+        }
+        let size = range.end - range.start;
+        if size == 0 {
+            return Ok(Vec::new());
+        }
+
+        let transfer_buffer = self
+            .create_transfer_buffer()
+            .with_size(size)
+            .with_usage(TransferBufferUsage::DOWNLOAD)
+            .build()?;
+
+        let command_buffer = self.acquire_command_buffer()?;
+        let copy_pass = self.begin_copy_pass(&command_buffer)?;
+        copy_pass.download_from_gpu_buffer(
+            BufferRegion::new()
+                .with_buffer(buffer)
+                .with_offset(range.start)
+                .with_size(size),
+            TransferBufferLocation::new()
+                .with_transfer_buffer(&transfer_buffer)
+                .with_offset(0),
+        );
+        self.end_copy_pass(copy_pass);
+
+        let fence = command_buffer.submit_and_acquire_fence(self)?;
+        self.wait_fences(true, &[fence])?;
+
+        let map = transfer_buffer.map::<u8>(self, false);
+        let bytes = map.mem().to_vec();
+        map.unmap();
+        Ok(bytes)
+    }
+
     #[doc(alias = "SDL_WaitForGPUFences")]
     pub fn wait_fences(&self, wait_all: bool, fences: &[Fence]) -> Result<(), Error> {
         let fences: Vec<_> = fences.iter().map(|x| x.raw()).collect();
@@ -290,6 +412,46 @@ impl Device {
         unsafe { std::mem::transmute(sys::gpu::SDL_GetGPUShaderFormats(self.raw())) }
     }
 
+    /// Returns whether `window` can present with `present_mode` on this device.
+    ///
+    /// [`PresentMode::Vsync`] is always supported. The window must have been claimed
+    /// by this device.
+    #[doc(alias = "SDL_WindowSupportsGPUPresentMode")]
+    pub fn window_supports_present_mode(&self, window: &Window, present_mode: PresentMode) -> bool {
+        unsafe {
+            sys::gpu::SDL_WindowSupportsGPUPresentMode(
+                self.raw(),
+                window.raw(),
+                sys::gpu::SDL_GPUPresentMode(present_mode as i32),
+            )
+        }
+    }
+
+    /// Returns whether `window` can use `swapchain_composition` on this device.
+    ///
+    /// [`SwapchainComposition::Sdr`] is always supported. The window must have been
+    /// claimed by this device.
+    #[doc(alias = "SDL_WindowSupportsGPUSwapchainComposition")]
+    pub fn window_supports_composition(
+        &self,
+        window: &Window,
+        swapchain_composition: SwapchainComposition,
+    ) -> bool {
+        unsafe {
+            sys::gpu::SDL_WindowSupportsGPUSwapchainComposition(
+                self.raw(),
+                window.raw(),
+                sys::gpu::SDL_GPUSwapchainComposition(swapchain_composition as i32),
+            )
+        }
+    }
+
+    /// Changes how the swapchain of a claimed `window` presents and composites.
+    ///
+    /// Returns an error if the combination is not supported; check with
+    /// [`Device::window_supports_present_mode`] and
+    /// [`Device::window_supports_composition`] first. Switching to
+    /// [`PresentMode::Immediate`] or [`PresentMode::Mailbox`] turns vsync off.
     #[doc(alias = "SDL_SetGPUSwapchainParameters")]
     pub fn set_swapchain_parameters(
         &self,
@@ -2,8 +2,8 @@ use crate::gpu::device::WeakDevice;
 
 mod buffer;
 pub use buffer::{
-    Buffer, BufferBinding, BufferBuilder, BufferMemMap, BufferRegion, TransferBuffer,
-    TransferBufferBuilder, TransferBufferLocation, VertexBufferDescription,
+    Buffer, BufferBinding, BufferBuilder, BufferLocation, BufferMemMap, BufferRegion,
+    TransferBuffer, TransferBufferBuilder, TransferBufferLocation, VertexBufferDescription,
 };
 
 mod device;
@@ -40,3 +40,8 @@ pub use texture::{
 
 mod shader;
 pub use shader::{Shader, ShaderBuilder};
+
+#[cfg(feature = "spirv-reflect")]
+mod reflect;
+#[cfg(feature = "spirv-reflect")]
+pub use reflect::ShaderResourceCounts;
@@ -1,23 +1,25 @@
-use super::{Buffer, ComputePipeline, Device, Filter, WeakDevice};
+use super::{Buffer, ComputePipeline, Device, Filter, Viewport, WeakDevice};
 use crate::{
     get_error,
     gpu::{
-        BufferBinding, BufferRegion, GraphicsPipeline, IndexElementSize, LoadOp, StoreOp, Texture,
-        TextureRegion, TextureSamplerBinding, TextureTransferInfo, TransferBufferLocation,
+        BufferBinding, BufferLocation, BufferRegion, GraphicsPipeline, IndexElementSize, LoadOp,
+        StoreOp, Texture, TextureRegion, TextureSamplerBinding, TextureTransferInfo,
+        TransferBufferLocation,
     },
-    pixels::Color,
+    pixels::{Color, FColor},
     rect::Rect,
     Error,
 };
 use std::sync::Arc;
 use sys::gpu::{
     SDL_AcquireGPUSwapchainTexture, SDL_BindGPUFragmentSamplers, SDL_BindGPUIndexBuffer,
-    SDL_BindGPUVertexBuffers, SDL_DrawGPUIndexedPrimitives, SDL_GPUBlitInfo, SDL_GPUBufferBinding,
-    SDL_GPUColorTargetInfo, SDL_GPUCommandBuffer, SDL_GPUComputePass, SDL_GPUCopyPass,
-    SDL_GPUDepthStencilTargetInfo, SDL_GPUFence, SDL_GPUFilter, SDL_GPULoadOp, SDL_GPURenderPass,
-    SDL_GPUTextureSamplerBinding, SDL_PushGPUComputeUniformData, SDL_PushGPUFragmentUniformData,
-    SDL_PushGPUVertexUniformData, SDL_QueryGPUFence, SDL_ReleaseGPUFence, SDL_UploadToGPUBuffer,
-    SDL_UploadToGPUTexture, SDL_WaitAndAcquireGPUSwapchainTexture,
+    SDL_BindGPUVertexBuffers, SDL_CopyGPUBufferToBuffer, SDL_DownloadFromGPUBuffer,
+    SDL_DrawGPUIndexedPrimitives, SDL_GPUBlitInfo, SDL_GPUBufferBinding, SDL_GPUColorTargetInfo,
+    SDL_GPUCommandBuffer, SDL_GPUComputePass, SDL_GPUCopyPass, SDL_GPUDepthStencilTargetInfo,
+    SDL_GPUFence, SDL_GPUFilter, SDL_GPULoadOp, SDL_GPURenderPass, SDL_GPUTextureSamplerBinding,
+    SDL_PushGPUComputeUniformData, SDL_PushGPUFragmentUniformData, SDL_PushGPUVertexUniformData,
+    SDL_QueryGPUFence, SDL_ReleaseGPUFence, SDL_UploadToGPUBuffer, SDL_UploadToGPUTexture,
+    SDL_WaitAndAcquireGPUSwapchainTexture,
 };
 
 /// Manages the raw `SDL_GPUFence` pointer and releases it on drop
@@ -106,6 +108,10 @@ impl CommandBuffer {
         }
     }
 
+    /// Blocks until a swapchain texture for `w` is available and returns it.
+    ///
+    /// The window must have been claimed with [`Device::claim_window`] or
+    /// [`Device::with_window`], otherwise an error is returned.
     #[doc(alias = "SDL_WaitAndAcquireGPUSwapchainTexture")]
     pub fn wait_and_acquire_swapchain_texture<'a>(
         &'a mut self,
@@ -143,6 +149,8 @@ impl CommandBuffer {
     /// let fence = command_buffer.submit_and_acquire_fence(&device)?;
     /// device.wait_fences(true, &[fence])?;
     /// ```
+    ///
+    /// Returns an error if the window has not been claimed by the device.
     #[doc(alias = "SDL_AcquireGPUSwapchainTexture")]
     pub fn acquire_swapchain_texture<'a>(
         &'a mut self,
@@ -500,10 +508,35 @@ impl RenderPass {
         }
     }
 
+    /// Sets the area of the render targets that subsequent draws map to.
+    ///
+    /// The viewport is reset to cover the whole target at the start of every render
+    /// pass, so it can be changed between draw calls to render several views into
+    /// one target.
+    #[doc(alias = "SDL_SetGPUViewport")]
+    pub fn set_viewport(&self, viewport: &Viewport) {
+        unsafe { sys::gpu::SDL_SetGPUViewport(self.inner, viewport.raw()) }
+    }
+
+    /// Restricts subsequent draws to `scissor`, in render target pixel coordinates.
     #[doc(alias = "SDL_SetGPUScissor")]
     pub fn set_scissor(&self, scissor: Rect) {
         unsafe { sys::gpu::SDL_SetGPUScissor(self.inner, scissor.raw()) }
     }
+
+    /// Sets the color used by the [`BlendFactor::ConstantColor`](super::BlendFactor::ConstantColor)
+    /// and [`BlendFactor::OneMinusConstantColor`](super::BlendFactor::OneMinusConstantColor)
+    /// blend factors.
+    #[doc(alias = "SDL_SetGPUBlendConstants")]
+    pub fn set_blend_constants(&self, blend_constants: FColor) {
+        unsafe { sys::gpu::SDL_SetGPUBlendConstants(self.inner, blend_constants.into()) }
+    }
+
+    /// Sets the reference value compared against the stencil buffer by stencil tests.
+    #[doc(alias = "SDL_SetGPUStencilReference")]
+    pub fn set_stencil_reference(&self, reference: u8) {
+        unsafe { sys::gpu::SDL_SetGPUStencilReference(self.inner, reference) }
+    }
 }
 
 pub struct CopyPass {
@@ -541,6 +574,35 @@ impl CopyPass {
     ) {
         unsafe { SDL_UploadToGPUTexture(self.raw(), &source.inner, &destination.inner, cycle) }
     }
+
+    /// Copies `size` bytes from one GPU buffer to another without going through
+    /// a transfer buffer.
+    #[doc(alias = "SDL_CopyGPUBufferToBuffer")]
+    pub fn copy_buffer_to_buffer(
+        &self,
+        source: BufferLocation,
+        destination: BufferLocation,
+        size: u32,
+        cycle: bool,
+    ) {
+        unsafe {
+            SDL_CopyGPUBufferToBuffer(self.raw(), &source.inner, &destination.inner, size, cycle)
+        }
+    }
+
+    /// Copies `source` from a GPU buffer into a transfer buffer created with
+    /// [`TransferBufferUsage::DOWNLOAD`](super::TransferBufferUsage::DOWNLOAD).
+    ///
+    /// The data is only available once the command buffer has been submitted and
+    /// its fence has signaled; map the transfer buffer after waiting on it.
+    #[doc(alias = "SDL_DownloadFromGPUBuffer")]
+    pub fn download_from_gpu_buffer(
+        &self,
+        source: BufferRegion,
+        destination: TransferBufferLocation,
+    ) {
+        unsafe { SDL_DownloadFromGPUBuffer(self.raw(), &source.inner, &destination.inner) }
+    }
 }
 
 pub struct ComputePass {
@@ -552,13 +614,18 @@ impl ComputePass {
         self.inner
     }
 
+    /// Binds the compute pipeline used by subsequent dispatches.
     #[doc(alias = "SDL_BindGPUComputePipeline")]
     pub fn bind_compute_pipeline(&self, pipeline: &ComputePipeline) {
         unsafe { sys::gpu::SDL_BindGPUComputePipeline(self.inner, pipeline.raw()) }
     }
 
+    /// Binds read-only storage buffers for the compute shader, starting at `first_slot`.
+    ///
+    /// Buffers the shader writes to are bound when the pass begins, see
+    /// [`Device::begin_compute_pass`].
     #[doc(alias = "SDL_BindGPUComputeStorageBuffers")]
-    pub fn bind_compute_storage_buffers(&self, first_slot: u32, storage_buffers: &[Buffer]) {
+    pub fn bind_storage_buffers(&self, first_slot: u32, storage_buffers: &[&Buffer]) {
         let buffer_handles = storage_buffers.iter().map(|x| x.raw()).collect::<Vec<_>>();
         unsafe {
             sys::gpu::SDL_BindGPUComputeStorageBuffers(
@@ -570,8 +637,9 @@ impl ComputePass {
         }
     }
 
+    /// Binds read-only storage textures for the compute shader, starting at `first_slot`.
     #[doc(alias = "SDL_BindGPUComputeStorageTextures")]
-    pub fn bind_compute_storage_textures(&self, first_slot: u32, storage_textures: &[Texture]) {
+    pub fn bind_storage_textures(&self, first_slot: u32, storage_textures: &[&Texture]) {
         let texture_handles = storage_textures.iter().map(|x| x.raw()).collect::<Vec<_>>();
         unsafe {
             sys::gpu::SDL_BindGPUComputeStorageTextures(
@@ -583,8 +651,9 @@ impl ComputePass {
         }
     }
 
+    /// Binds texture-sampler pairs for the compute shader, starting at `first_slot`.
     #[doc(alias = "SDL_BindGPUComputeSamplers")]
-    pub fn bind_compute_samplers(&self, first_slot: u32, bindings: &[TextureSamplerBinding]) {
+    pub fn bind_samplers(&self, first_slot: u32, bindings: &[TextureSamplerBinding]) {
         unsafe {
             sys::gpu::SDL_BindGPUComputeSamplers(
                 self.inner,
@@ -595,10 +664,40 @@ impl ComputePass {
         }
     }
 
+    #[deprecated(since = "0.18.5", note = "Use bind_storage_buffers instead.")]
+    pub fn bind_compute_storage_buffers(&self, first_slot: u32, storage_buffers: &[Buffer]) {
+        self.bind_storage_buffers(first_slot, &storage_buffers.iter().collect::<Vec<_>>())
+    }
+
+    #[deprecated(since = "0.18.5", note = "Use bind_storage_textures instead.")]
+    pub fn bind_compute_storage_textures(&self, first_slot: u32, storage_textures: &[Texture]) {
+        self.bind_storage_textures(first_slot, &storage_textures.iter().collect::<Vec<_>>())
+    }
+
+    #[deprecated(since = "0.18.5", note = "Use bind_samplers instead.")]
+    pub fn bind_compute_samplers(&self, first_slot: u32, bindings: &[TextureSamplerBinding]) {
+        self.bind_samplers(first_slot, bindings)
+    }
+
+    /// Dispatches compute work with the given number of workgroups in each dimension.
+    ///
+    /// A compute pipeline must have been bound with [`ComputePass::bind_compute_pipeline`]
+    /// beforehand, along with any resources it reads. The total thread count in each
+    /// dimension is the group count multiplied by the pipeline's thread count.
     #[doc(alias = "SDL_DispatchGPUCompute")]
     pub fn dispatch(&self, groupcount_x: u32, groupcount_y: u32, groupcount_z: u32) {
         unsafe {
             sys::gpu::SDL_DispatchGPUCompute(self.inner, groupcount_x, groupcount_y, groupcount_z)
         }
     }
+
+    /// Dispatches compute work using workgroup counts read from `buffer` on the GPU.
+    ///
+    /// At `offset`, the buffer must hold three consecutive `u32` values for the x, y
+    /// and z group counts. The buffer needs to have been created with
+    /// [`BufferUsageFlags::INDIRECT`](super::BufferUsageFlags::INDIRECT).
+    #[doc(alias = "SDL_DispatchGPUComputeIndirect")]
+    pub fn dispatch_indirect(&self, buffer: &Buffer, offset: u32) {
+        unsafe { sys::gpu::SDL_DispatchGPUComputeIndirect(self.inner, buffer.raw(), offset) }
+    }
 }
@@ -64,21 +64,34 @@ impl<'a> GraphicsPipelineTargetInfo<'a> {
 pub struct GraphicsPipelineBuilder<'a> {
     device: &'a Device,
     inner: SDL_GPUGraphicsPipelineCreateInfo,
+    cached: bool,
+    shaders: Vec<Shader>,
 }
 impl<'a> GraphicsPipelineBuilder<'a> {
     pub(super) fn new(device: &'a Device) -> Self {
         Self {
             device,
             inner: Default::default(),
+            cached: false,
+            shaders: Vec::new(),
+        }
+    }
+
+    pub(super) fn new_cached(device: &'a Device) -> Self {
+        Self {
+            cached: true,
+            ..Self::new(device)
         }
     }
 
     pub fn with_fragment_shader(mut self, value: &'a Shader) -> Self {
         self.inner.fragment_shader = value.raw();
+        self.shaders.push(value.clone());
         self
     }
     pub fn with_vertex_shader(mut self, value: &'a Shader) -> Self {
         self.inner.vertex_shader = value.raw();
+        self.shaders.push(value.clone());
         self
     }
     pub fn with_primitive_type(mut self, value: PrimitiveType) -> Self {
@@ -119,6 +132,8 @@ impl<'a> GraphicsPipelineBuilder<'a> {
         GraphicsPipelineBuilder {
             device: self.device,
             inner,
+            cached: self.cached,
+            shaders: self.shaders,
         }
     }
 
@@ -134,10 +149,36 @@ impl<'a> GraphicsPipelineBuilder<'a> {
         GraphicsPipelineBuilder {
             device: self.device,
             inner,
+            cached: self.cached,
+            shaders: self.shaders,
         }
     }
 
     pub fn build(self) -> Result<GraphicsPipeline, Error> {
+        if !self.cached {
+            return self.build_uncached();
+        }
+        let key = self.cache_key();
+        let mut cache = self
+            .device
+            .graphics_pipeline_cache()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if let Some(entry) = cache.get(&key) {
+            return Ok(entry.pipeline.clone());
+        }
+        let pipeline = self.build_uncached()?;
+        cache.insert(
+            key,
+            CachedGraphicsPipeline {
+                pipeline: pipeline.clone(),
+                shaders: self.shaders,
+            },
+        );
+        Ok(pipeline)
+    }
+
+    fn build_uncached(&self) -> Result<GraphicsPipeline, Error> {
         let raw_pipeline =
             unsafe { sys::gpu::SDL_CreateGPUGraphicsPipeline(self.device.raw(), &self.inner) };
         if raw_pipeline.is_null() {
@@ -151,6 +192,140 @@ impl<'a> GraphicsPipelineBuilder<'a> {
             })
         }
     }
+
+    /// Collects everything in the create-info that affects the resulting pipeline,
+    /// following the pointers to the vertex input and color target arrays.
+    fn cache_key(&self) -> GraphicsPipelineKey {
+        let info = &self.inner;
+        let mut key = Vec::new();
+
+        // The cache keeps the shaders alive, so their addresses identify them.
+        key.extend([info.vertex_shader as u64, info.fragment_shader as u64]);
+
+        let vertex_input = &info.vertex_input_state;
+        let buffers = unsafe {
+            slice_or_empty(
+                vertex_input.vertex_buffer_descriptions,
+                vertex_input.num_vertex_buffers,
+            )
+        };
+        key.push(buffers.len() as u64);
+        for desc in buffers {
+            key.extend([
+                desc.slot as u64,
+                desc.pitch as u64,
+                desc.input_rate.0 as u64,
+                desc.instance_step_rate as u64,
+            ]);
+        }
+        let attributes = unsafe {
+            slice_or_empty(
+                vertex_input.vertex_attributes,
+                vertex_input.num_vertex_attributes,
+            )
+        };
+        key.push(attributes.len() as u64);
+        for attr in attributes {
+            key.extend([
+                attr.location as u64,
+                attr.buffer_slot as u64,
+                attr.format.0 as u64,
+                attr.offset as u64,
+            ]);
+        }
+
+        key.push(info.primitive_type.0 as u64);
+
+        let rs = &info.rasterizer_state;
+        key.extend([
+            rs.fill_mode.0 as u64,
+            rs.cull_mode.0 as u64,
+            rs.front_face.0 as u64,
+            rs.depth_bias_constant_factor.to_bits() as u64,
+            rs.depth_bias_clamp.to_bits() as u64,
+            rs.depth_bias_slope_factor.to_bits() as u64,
+            rs.enable_depth_bias as u64,
+            rs.enable_depth_clip as u64,
+        ]);
+
+        let ms = &info.multisample_state;
+        key.extend([
+            ms.sample_count.0 as u64,
+            ms.sample_mask as u64,
+            ms.enable_mask as u64,
+        ]);
+
+        let ds = &info.depth_stencil_state;
+        for stencil in [&ds.back_stencil_state, &ds.front_stencil_state] {
+            key.extend([
+                stencil.fail_op.0 as u64,
+                stencil.pass_op.0 as u64,
+                stencil.depth_fail_op.0 as u64,
+                stencil.compare_op.0 as u64,
+            ]);
+        }
+        key.extend([
+            ds.compare_op.0 as u64,
+            ds.compare_mask as u64,
+            ds.write_mask as u64,
+            ds.enable_depth_test as u64,
+            ds.enable_depth_write as u64,
+            ds.enable_stencil_test as u64,
+        ]);
+
+        let target = &info.target_info;
+        let color_targets =
+            unsafe { slice_or_empty(target.color_target_descriptions, target.num_color_targets) };
+        key.push(color_targets.len() as u64);
+        for desc in color_targets {
+            let blend = &desc.blend_state;
+            key.extend([
+                desc.format.0 as u64,
+                blend.src_color_blendfactor.0 as u64,
+                blend.dst_color_blendfactor.0 as u64,
+                blend.color_blend_op.0 as u64,
+                blend.src_alpha_blendfactor.0 as u64,
+                blend.dst_alpha_blendfactor.0 as u64,
+                blend.alpha_blend_op.0 as u64,
+                blend.color_write_mask.0 as u64,
+                blend.enable_blend as u64,
+                blend.enable_color_write_mask as u64,
+            ]);
+        }
+        key.extend([
+            target.depth_stencil_format.0 as u64,
+            target.has_depth_stencil_target as u64,
+        ]);
+
+        key.push(info.props.0 as u64);
+        GraphicsPipelineKey(key)
+    }
+}
+
+/// The full state a cached graphics pipeline was created from. Lookups compare
+/// it field by field, so distinct create-infos never share a pipeline.
+#[derive(PartialEq, Eq, Hash)]
+pub(super) struct GraphicsPipelineKey(Vec<u64>);
+
+/// A cached graphics pipeline, along with the shaders it was built from.
+///
+/// Holding the shaders keeps their addresses, which are part of the key, from
+/// being reused by new shaders while the entry exists.
+pub(super) struct CachedGraphicsPipeline {
+    pub(super) pipeline: GraphicsPipeline,
+    pub(super) shaders: Vec<Shader>,
+}
+
+/// Views a pointer and length from an SDL create-info as a slice.
+///
+/// # Safety
+/// `ptr` must point to `len` valid elements, or `len` must be zero.
+unsafe fn slice_or_empty<'a, T>(ptr: *const T, len: u32) -> &'a [T] {
+    if ptr.is_null() || len == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(ptr, len as usize)
+    }
 }
 
 /// Manages the raw `SDL_GPUGraphicsPipeline` pointer and releases it on drop
@@ -0,0 +1,128 @@
+//! Minimal SPIR-V reflection, used to fill in the resource counts SDL needs when
+//! creating a shader.
+
+use crate::Error;
+use std::collections::{HashMap, HashSet};
+
+const MAGIC: u32 = 0x0723_0203;
+const HEADER_WORDS: usize = 5;
+
+const OP_DECORATE: u32 = 71;
+const OP_TYPE_IMAGE: u32 = 25;
+const OP_TYPE_SAMPLED_IMAGE: u32 = 27;
+const OP_TYPE_ARRAY: u32 = 28;
+const OP_TYPE_RUNTIME_ARRAY: u32 = 29;
+const OP_TYPE_POINTER: u32 = 32;
+const OP_VARIABLE: u32 = 59;
+
+const DECORATION_BLOCK: u32 = 2;
+const DECORATION_BUFFER_BLOCK: u32 = 3;
+
+const STORAGE_CLASS_UNIFORM_CONSTANT: u32 = 0;
+const STORAGE_CLASS_UNIFORM: u32 = 2;
+const STORAGE_CLASS_STORAGE_BUFFER: u32 = 12;
+
+/// The number of each kind of resource a shader binds, as expected by
+/// [`ShaderBuilder`](super::ShaderBuilder).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ShaderResourceCounts {
+    pub samplers: u32,
+    pub storage_textures: u32,
+    pub storage_buffers: u32,
+    pub uniform_buffers: u32,
+}
+
+enum Type {
+    Image { sampled: u32 },
+    SampledImage,
+    Array(u32),
+    Pointer(u32),
+}
+
+impl ShaderResourceCounts {
+    /// Counts the resources declared by a SPIR-V module.
+    ///
+    /// Each resource variable counts once, following SDL's convention that every
+    /// binding holds a single resource. Returns an error if `code` is not SPIR-V.
+    pub fn from_spirv(code: &[u8]) -> Result<Self, Error> {
+        if code.len() % 4 != 0 || code.len() < HEADER_WORDS * 4 {
+            return Err(Error("SPIR-V code has an invalid length".to_owned()));
+        }
+        let mut words: Vec<u32> = code
+            .chunks_exact(4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect();
+        if words[0] == MAGIC.swap_bytes() {
+            words.iter_mut().for_each(|w| *w = w.swap_bytes());
+        } else if words[0] != MAGIC {
+            return Err(Error("SPIR-V code has an invalid magic number".to_owned()));
+        }
+
+        let mut types = HashMap::new();
+        let mut blocks = HashSet::new();
+        let mut buffer_blocks = HashSet::new();
+        let mut variables = Vec::new();
+
+        let mut i = HEADER_WORDS;
+        while i < words.len() {
+            let word_count = (words[i] >> 16) as usize;
+            let opcode = words[i] & 0xffff;
+            if word_count == 0 || i + word_count > words.len() {
+                return Err(Error("SPIR-V code has a malformed instruction".to_owned()));
+            }
+            let ops = &words[i + 1..i + word_count];
+            match (opcode, ops) {
+                (OP_DECORATE, [target, DECORATION_BLOCK, ..]) => {
+                    blocks.insert(*target);
+                }
+                (OP_DECORATE, [target, DECORATION_BUFFER_BLOCK, ..]) => {
+                    buffer_blocks.insert(*target);
+                }
+                (OP_TYPE_IMAGE, [id, _, _, _, _, _, sampled, ..]) => {
+                    types.insert(*id, Type::Image { sampled: *sampled });
+                }
+                (OP_TYPE_SAMPLED_IMAGE, [id, ..]) => {
+                    types.insert(*id, Type::SampledImage);
+                }
+                (OP_TYPE_ARRAY | OP_TYPE_RUNTIME_ARRAY, [id, element, ..]) => {
+                    types.insert(*id, Type::Array(*element));
+                }
+                (OP_TYPE_POINTER, [id, _, pointee]) => {
+                    types.insert(*id, Type::Pointer(*pointee));
+                }
+                (OP_VARIABLE, [result_type, _, storage_class, ..]) => {
+                    variables.push((*result_type, *storage_class));
+                }
+                _ => {}
+            }
+            i += word_count;
+        }
+
+        // Strips pointers and arrays down to the underlying resource type.
+        let base_type = |mut id: u32| {
+            while let Some(Type::Pointer(inner) | Type::Array(inner)) = types.get(&id) {
+                id = *inner;
+            }
+            id
+        };
+
+        let mut counts = Self::default();
+        for (result_type, storage_class) in variables {
+            let base = base_type(result_type);
+            match storage_class {
+                STORAGE_CLASS_UNIFORM_CONSTANT => match types.get(&base) {
+                    Some(Type::SampledImage) => counts.samplers += 1,
+                    Some(Type::Image { sampled: 2 }) => counts.storage_textures += 1,
+                    _ => {}
+                },
+                STORAGE_CLASS_UNIFORM if buffer_blocks.contains(&base) => {
+                    counts.storage_buffers += 1
+                }
+                STORAGE_CLASS_UNIFORM if blocks.contains(&base) => counts.uniform_buffers += 1,
+                STORAGE_CLASS_STORAGE_BUFFER => counts.storage_buffers += 1,
+                _ => {}
+            }
+        }
+        Ok(counts)
+    }
+}
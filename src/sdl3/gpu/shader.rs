@@ -69,6 +69,25 @@ impl<'a> ShaderBuilder<'a> {
         self.inner.stage = unsafe { std::mem::transmute(stage as u32) };
         self
     }
+    /// Sets SPIR-V `code` for the given stage.
+    ///
+    /// With the `spirv-reflect` feature enabled, the sampler, storage texture, storage
+    /// buffer and uniform buffer counts are also read from the code, replacing any set
+    /// earlier; call the `with_*` count setters afterwards to override them. If the code
+    /// can't be parsed, or the feature is disabled, the counts are left as they are and
+    /// have to be set by hand.
+    pub fn with_spirv(mut self, code: &'a [u8], stage: ShaderStage) -> Self {
+        #[cfg(feature = "spirv-reflect")]
+        if let Ok(counts) = super::ShaderResourceCounts::from_spirv(code) {
+            self.inner.num_samplers = counts.samplers;
+            self.inner.num_storage_textures = counts.storage_textures;
+            self.inner.num_storage_buffers = counts.storage_buffers;
+            self.inner.num_uniform_buffers = counts.uniform_buffers;
+        }
+        self = self.with_code(ShaderFormat::SPIRV, code, stage);
+        self
+    }
+
     pub fn with_entrypoint(mut self, entry_point: &'a CStr) -> Self {
         self.inner.entrypoint = entry_point.as_ptr();
         self
@@ -1,6 +1,8 @@
 use libc::c_char;
 use std::ffi::{CStr, CString, NulError};
-use std::fmt::{Display, Error, Formatter};
+use std::fmt::{Debug, Display, Error, Formatter};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 use sys::guid::SDL_GUID;
 
 /// Wrapper around a `SDL_GUID`, a globally unique identifier
@@ -18,8 +20,23 @@ impl PartialEq for Guid {
 
 impl Eq for Guid {}
 
+impl Hash for Guid {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.data.hash(state);
+    }
+}
+
+impl Debug for Guid {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "Guid({})", self.string())
+    }
+}
+
 impl Guid {
     /// Create a GUID from a string representation.
+    ///
+    /// SDL doesn't validate the string: invalid hex digits are read as 0.
+    /// Use [`str::parse`] to reject malformed GUIDs.
     #[doc(alias = "SDL_StringToGUID")]
     pub fn from_string(guid: &str) -> Result<Guid, NulError> {
         let guid = CString::new(guid)?;
@@ -76,6 +93,21 @@ impl Guid {
     }
 }
 
+/// Parses the 32 hex digit representation returned by [`Guid::string`].
+impl FromStr for Guid {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Guid, crate::Error> {
+        if s.len() != 32 || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(crate::Error(format!(
+                "invalid GUID {s:?}: expected 32 hex digits"
+            )));
+        }
+        // Can't contain NUL after the check above.
+        Ok(Guid::from_string(s).unwrap())
+    }
+}
+
 impl Display for Guid {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
         write!(f, "{}", self.string())
@@ -1,10 +1,13 @@
 //! Haptic Functions
 
+use std::time::Duration;
+
 use crate::sys;
 use sys::joystick::SDL_OpenJoystick;
 
 use crate::common::IntegerOrSdlError;
 use crate::get_error;
+use crate::Error;
 use crate::HapticSubsystem;
 
 impl HapticSubsystem {
@@ -53,6 +56,59 @@ impl Haptic {
     pub fn rumble_stop(&mut self) {
         unsafe { sys::haptic::SDL_StopHapticRumble(self.raw) };
     }
+
+    /// Return whether the device can play `effect`.
+    #[doc(alias = "SDL_HapticEffectSupported")]
+    pub fn effect_supported(&self, effect: &HapticEffect) -> bool {
+        let raw = effect.to_ll();
+        unsafe { sys::haptic::SDL_HapticEffectSupported(self.raw, &raw) }
+    }
+
+    /// Upload `effect` to the device so it can be played with
+    /// [`Haptic::run_effect`].
+    ///
+    /// The effect stays on the device until [`Haptic::destroy_effect`] is
+    /// called or the device is closed.
+    #[doc(alias = "SDL_CreateHapticEffect")]
+    pub fn upload_effect(&self, effect: &HapticEffect) -> Result<HapticEffectId, Error> {
+        let raw = effect.to_ll();
+        let id = unsafe { sys::haptic::SDL_CreateHapticEffect(self.raw, &raw) };
+        if id < 0 {
+            Err(get_error())
+        } else {
+            Ok(HapticEffectId(id))
+        }
+    }
+
+    /// Play an uploaded effect `iterations` times.
+    ///
+    /// Pass [`HAPTIC_INFINITY`] to repeat the effect until it is stopped.
+    #[doc(alias = "SDL_RunHapticEffect")]
+    pub fn run_effect(&self, effect: HapticEffectId, iterations: u32) -> Result<(), Error> {
+        let result = unsafe { sys::haptic::SDL_RunHapticEffect(self.raw, effect.0, iterations) };
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Stop an uploaded effect that is currently playing.
+    #[doc(alias = "SDL_StopHapticEffect")]
+    pub fn stop_effect(&self, effect: HapticEffectId) -> Result<(), Error> {
+        let result = unsafe { sys::haptic::SDL_StopHapticEffect(self.raw, effect.0) };
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Remove an uploaded effect from the device, stopping it if needed.
+    #[doc(alias = "SDL_DestroyHapticEffect")]
+    pub fn destroy_effect(&self, effect: HapticEffectId) {
+        unsafe { sys::haptic::SDL_DestroyHapticEffect(self.raw, effect.0) };
+    }
 }
 
 impl Drop for Haptic {
@@ -61,3 +117,194 @@ impl Drop for Haptic {
         unsafe { sys::haptic::SDL_CloseHaptic(self.raw) }
     }
 }
+
+/// Length or iteration count meaning "play until stopped".
+pub const HAPTIC_INFINITY: u32 = sys::haptic::SDL_HAPTIC_INFINITY;
+
+/// Handle to an effect uploaded with [`Haptic::upload_effect`].
+///
+/// Only valid for the device that created it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HapticEffectId(i32);
+
+/// Direction an effect comes from, as seen by the player.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HapticDirection {
+    /// Angle in hundredths of a degree, clockwise from north (0 to 35999).
+    Polar(i32),
+    /// A vector in the device's `x`, `y`, `z` axes.
+    Cartesian(i32, i32, i32),
+    /// Azimuth and elevation in hundredths of a degree.
+    Spherical(i32, i32),
+    /// Use the first axis of a steering wheel.
+    SteeringAxis,
+}
+
+impl Default for HapticDirection {
+    fn default() -> Self {
+        HapticDirection::Polar(0)
+    }
+}
+
+impl HapticDirection {
+    fn to_ll(self) -> sys::haptic::SDL_HapticDirection {
+        let (kind, dir) = match self {
+            HapticDirection::Polar(angle) => (sys::haptic::SDL_HAPTIC_POLAR, [angle, 0, 0]),
+            HapticDirection::Cartesian(x, y, z) => (sys::haptic::SDL_HAPTIC_CARTESIAN, [x, y, z]),
+            HapticDirection::Spherical(azimuth, elevation) => {
+                (sys::haptic::SDL_HAPTIC_SPHERICAL, [azimuth, elevation, 0])
+            }
+            HapticDirection::SteeringAxis => (sys::haptic::SDL_HAPTIC_STEERING_AXIS, [0, 0, 0]),
+        };
+        sys::haptic::SDL_HapticDirection {
+            r#type: kind as _,
+            dir,
+        }
+    }
+}
+
+/// Wave shape of a periodic effect.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Waveform {
+    Sine,
+    Square,
+    Triangle,
+    SawtoothUp,
+    SawtoothDown,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+enum EffectKind {
+    Constant {
+        level: i16,
+    },
+    Periodic {
+        waveform: Waveform,
+        period: u16,
+        magnitude: i16,
+    },
+    Ramp {
+        start: i16,
+        end: i16,
+    },
+}
+
+/// A force feedback effect that can be uploaded to a [`Haptic`] device.
+///
+/// ```no_run
+/// use sdl3::haptic::{HapticDirection, HapticEffect};
+/// use std::time::Duration;
+///
+/// let effect = HapticEffect::constant(0x4000, Duration::from_millis(500))
+///     .direction(HapticDirection::Polar(9000))
+///     .delay(Duration::from_millis(100));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HapticEffect {
+    kind: EffectKind,
+    direction: HapticDirection,
+    length: u32,
+    delay: u16,
+}
+
+impl HapticEffect {
+    fn new(kind: EffectKind, length: Duration) -> HapticEffect {
+        HapticEffect {
+            kind,
+            direction: HapticDirection::default(),
+            length: u32::try_from(length.as_millis()).unwrap_or(HAPTIC_INFINITY),
+            delay: 0,
+        }
+    }
+
+    /// A constant force of strength `level` for `length`.
+    #[doc(alias = "SDL_HapticConstant")]
+    pub fn constant(level: i16, length: Duration) -> HapticEffect {
+        HapticEffect::new(EffectKind::Constant { level }, length)
+    }
+
+    /// A force oscillating with `waveform` every `period`, peaking at
+    /// `magnitude`, for `length`.
+    #[doc(alias = "SDL_HapticPeriodic")]
+    pub fn periodic(
+        waveform: Waveform,
+        period: Duration,
+        magnitude: i16,
+        length: Duration,
+    ) -> HapticEffect {
+        let period = u16::try_from(period.as_millis()).unwrap_or(u16::MAX);
+        HapticEffect::new(
+            EffectKind::Periodic {
+                waveform,
+                period,
+                magnitude,
+            },
+            length,
+        )
+    }
+
+    /// A force going linearly from `start` to `end` over `length`.
+    #[doc(alias = "SDL_HapticRamp")]
+    pub fn ramp(start: i16, end: i16, length: Duration) -> HapticEffect {
+        HapticEffect::new(EffectKind::Ramp { start, end }, length)
+    }
+
+    /// Set the direction the effect comes from.
+    pub fn direction(mut self, direction: HapticDirection) -> HapticEffect {
+        self.direction = direction;
+        self
+    }
+
+    /// Set how long to wait before the effect starts.
+    pub fn delay(mut self, delay: Duration) -> HapticEffect {
+        self.delay = u16::try_from(delay.as_millis()).unwrap_or(u16::MAX);
+        self
+    }
+
+    fn to_ll(&self) -> sys::haptic::SDL_HapticEffect {
+        // SAFETY: SDL_HapticEffect is a plain C union, all-zero is valid.
+        let mut raw: sys::haptic::SDL_HapticEffect = unsafe { std::mem::zeroed() };
+        let direction = self.direction.to_ll();
+
+        match self.kind {
+            EffectKind::Constant { level } => {
+                let constant = unsafe { &mut raw.constant };
+                constant.r#type = sys::haptic::SDL_HAPTIC_CONSTANT as _;
+                constant.direction = direction;
+                constant.length = self.length;
+                constant.delay = self.delay;
+                constant.level = level;
+            }
+            EffectKind::Periodic {
+                waveform,
+                period,
+                magnitude,
+            } => {
+                let periodic = unsafe { &mut raw.periodic };
+                periodic.r#type = match waveform {
+                    Waveform::Sine => sys::haptic::SDL_HAPTIC_SINE,
+                    Waveform::Square => sys::haptic::SDL_HAPTIC_SQUARE,
+                    Waveform::Triangle => sys::haptic::SDL_HAPTIC_TRIANGLE,
+                    Waveform::SawtoothUp => sys::haptic::SDL_HAPTIC_SAWTOOTHUP,
+                    Waveform::SawtoothDown => sys::haptic::SDL_HAPTIC_SAWTOOTHDOWN,
+                } as _;
+                periodic.direction = direction;
+                periodic.length = self.length;
+                periodic.delay = self.delay;
+                periodic.period = period;
+                periodic.magnitude = magnitude;
+            }
+            EffectKind::Ramp { start, end } => {
+                let ramp = unsafe { &mut raw.ramp };
+                ramp.r#type = sys::haptic::SDL_HAPTIC_RAMP as _;
+                ramp.direction = direction;
+                ramp.length = self.length;
+                ramp.delay = self.delay;
+                ramp.start = start;
+                ramp.end = end;
+            }
+        }
+
+        raw
+    }
+}
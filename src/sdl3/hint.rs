@@ -1,9 +1,27 @@
+//! Configuration hints
+//!
+//! Hints are set by name. Use the constants in [`names`] rather than string
+//! literals, so a typo is a compile error instead of a hint silently doing
+//! nothing:
+//!
+//! ```rust,no_run
+//! use sdl3::hint::{self, names};
+//!
+//! hint::set(names::RENDER_DRIVER, "vulkan");
+//! hint::set(names::RENDER_VSYNC, "1");
+//! hint::set(names::APP_NAME, "My Game");
+//! ```
+
+use crate::get_error;
 use crate::sys;
-use libc::c_char;
+use crate::Error;
+use libc::{c_char, c_void};
 use std::ffi::{CStr, CString};
 
+/// Names of every hint known to SDL, generated from the SDL headers.
 pub mod names;
 
+/// Priority of a hint value, see [`set_with_priority`].
 pub enum Hint {
     Default,
     Normal,
@@ -129,3 +147,146 @@ pub fn set_with_priority(name: &str, value: &str, priority: &Hint) -> bool {
         )
     }
 }
+
+/// Reset a hint to its default value, which is the value of the environment
+/// variable of the same name if set.
+///
+/// Returns `false` if the hint name is invalid.
+#[doc(alias = "SDL_ResetHint")]
+pub fn reset(name: &str) -> bool {
+    let Ok(name) = CString::new(name) else {
+        return false;
+    };
+    unsafe { sys::hints::SDL_ResetHint(name.as_ptr() as *const c_char) }
+}
+
+/// Reset every hint to its default value.
+#[doc(alias = "SDL_ResetHints")]
+pub fn reset_all() {
+    unsafe { sys::hints::SDL_ResetHints() }
+}
+
+/// Sets a hint for as long as it is alive, and [`reset`]s it when dropped.
+///
+/// ```rust,no_run
+/// use sdl3::hint::{names, HintGuard};
+///
+/// {
+///     let _guard = HintGuard::new(names::RENDER_DRIVER, "software");
+///     // Renderers created here use the software driver.
+/// }
+/// // The hint is back to its default value.
+/// ```
+#[must_use = "the hint is reset as soon as the guard is dropped"]
+pub struct HintGuard {
+    name: String,
+}
+
+impl HintGuard {
+    /// Set `name` to `value` with normal priority.
+    pub fn new(name: &str, value: &str) -> HintGuard {
+        set(name, value);
+        HintGuard {
+            name: name.to_owned(),
+        }
+    }
+
+    /// Set `name` to `value` with the given `priority`.
+    pub fn with_priority(name: &str, value: &str, priority: &Hint) -> HintGuard {
+        set_with_priority(name, value, priority);
+        HintGuard {
+            name: name.to_owned(),
+        }
+    }
+}
+
+impl Drop for HintGuard {
+    fn drop(&mut self) {
+        reset(&self.name);
+    }
+}
+
+unsafe fn optional_str<'a>(value: *const c_char) -> Option<std::borrow::Cow<'a, str>> {
+    if value.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(value).to_string_lossy())
+    }
+}
+
+unsafe extern "C" fn hint_callback<F>(
+    userdata: *mut c_void,
+    name: *const c_char,
+    old_value: *const c_char,
+    new_value: *const c_char,
+) where
+    F: FnMut(&str, Option<&str>, Option<&str>),
+{
+    let callback = &mut *(userdata as *mut F);
+    let name = optional_str(name).unwrap_or_default();
+    let old_value = optional_str(old_value);
+    let new_value = optional_str(new_value);
+    callback(&name, old_value.as_deref(), new_value.as_deref());
+}
+
+/// Watch a hint for changes.
+///
+/// `callback` receives the hint name, the old value and the new value. It is
+/// called once right away with the current value, then on every change,
+/// from whichever thread changed the hint. It is removed when the returned
+/// [`HintCallback`] is dropped.
+///
+/// ```rust,no_run
+/// use sdl3::hint::{self, names};
+///
+/// let _watch = hint::add_callback(names::RENDER_VSYNC, |_name, _old, new| {
+///     println!("vsync is now {new:?}");
+/// })
+/// .unwrap();
+/// ```
+#[doc(alias = "SDL_AddHintCallback")]
+pub fn add_callback<F>(name: &str, callback: F) -> Result<HintCallback, Error>
+where
+    F: FnMut(&str, Option<&str>, Option<&str>) + Send + 'static,
+{
+    let name =
+        CString::new(name).map_err(|_| Error("hint name must not contain NUL bytes".to_owned()))?;
+    let userdata = Box::into_raw(Box::new(callback)) as *mut c_void;
+    let raw_callback: sys::hints::SDL_HintCallback = Some(hint_callback::<F>);
+
+    let result = unsafe { sys::hints::SDL_AddHintCallback(name.as_ptr(), raw_callback, userdata) };
+    if result {
+        Ok(HintCallback {
+            name,
+            callback: raw_callback,
+            userdata,
+            drop_userdata: drop_boxed::<F>,
+        })
+    } else {
+        unsafe { drop_boxed::<F>(userdata) };
+        Err(get_error())
+    }
+}
+
+unsafe fn drop_boxed<F>(userdata: *mut c_void) {
+    drop(Box::from_raw(userdata as *mut F));
+}
+
+/// A callback registered with [`add_callback`], removed when dropped.
+#[must_use = "the callback is removed as soon as this is dropped"]
+pub struct HintCallback {
+    name: CString,
+    callback: sys::hints::SDL_HintCallback,
+    userdata: *mut c_void,
+    drop_userdata: unsafe fn(*mut c_void),
+}
+
+impl Drop for HintCallback {
+    #[doc(alias = "SDL_RemoveHintCallback")]
+    fn drop(&mut self) {
+        unsafe {
+            sys::hints::SDL_RemoveHintCallback(self.name.as_ptr(), self.callback, self.userdata);
+            (self.drop_userdata)(self.userdata);
+        }
+    }
+}
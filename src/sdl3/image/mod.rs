@@ -22,7 +22,7 @@
 
 use crate::iostream::IOStream;
 use crate::render::{Texture, TextureCreator};
-use crate::surface::Surface;
+use crate::surface::{Surface, SurfaceRef};
 use crate::version::Version;
 use crate::{get_error, Error};
 use sdl3_image_sys::image;
@@ -46,6 +46,22 @@ pub trait SaveSurface {
     fn save_io(&self, dst: &mut IOStream) -> Result<(), Error>;
 }
 
+/// Method extensions to Surface for saving to lossy formats at a given quality
+pub trait SaveSurfaceLossy {
+    /// Saves as JPG. `quality` is clamped to `0..=100`.
+    fn save_jpg<P: AsRef<Path>>(&self, filename: P, quality: i32) -> Result<(), Error>;
+    /// Saves as JPG to an IOStream. `quality` is clamped to `0..=100`.
+    fn save_jpg_io(&self, dst: &mut IOStream, quality: i32) -> Result<(), Error>;
+    /// Saves as AVIF. `quality` is clamped to `0..=100`.
+    ///
+    /// Returns an error if the linked `SDL_image` was built without AVIF support.
+    fn save_avif<P: AsRef<Path>>(&self, filename: P, quality: i32) -> Result<(), Error>;
+    /// Saves as AVIF to an IOStream. `quality` is clamped to `0..=100`.
+    ///
+    /// Returns an error if the linked `SDL_image` was built without AVIF support.
+    fn save_avif_io(&self, dst: &mut IOStream, quality: i32) -> Result<(), Error>;
+}
+
 impl<'a> LoadSurface for Surface<'a> {
     fn from_file<P: AsRef<Path>>(filename: P) -> Result<Surface<'a>, Error> {
         //! Loads an SDL Surface from a file
@@ -98,10 +114,59 @@ impl<'a> SaveSurface for Surface<'a> {
     }
 }
 
+impl<'a> SaveSurfaceLossy for Surface<'a> {
+    #[doc(alias = "IMG_SaveJPG")]
+    fn save_jpg<P: AsRef<Path>>(&self, filename: P, quality: i32) -> Result<(), Error> {
+        unsafe {
+            let c_filename = CString::new(filename.as_ref().to_str().unwrap()).unwrap();
+            if image::IMG_SaveJPG(self.raw(), c_filename.as_ptr(), quality.clamp(0, 100)) {
+                Ok(())
+            } else {
+                Err(get_error())
+            }
+        }
+    }
+
+    #[doc(alias = "IMG_SaveJPG_IO")]
+    fn save_jpg_io(&self, dst: &mut IOStream, quality: i32) -> Result<(), Error> {
+        unsafe {
+            if image::IMG_SaveJPG_IO(self.raw(), dst.raw(), false, quality.clamp(0, 100)) {
+                Ok(())
+            } else {
+                Err(get_error())
+            }
+        }
+    }
+
+    #[doc(alias = "IMG_SaveAVIF")]
+    fn save_avif<P: AsRef<Path>>(&self, filename: P, quality: i32) -> Result<(), Error> {
+        unsafe {
+            let c_filename = CString::new(filename.as_ref().to_str().unwrap()).unwrap();
+            if image::IMG_SaveAVIF(self.raw(), c_filename.as_ptr(), quality.clamp(0, 100)) {
+                Ok(())
+            } else {
+                Err(get_error())
+            }
+        }
+    }
+
+    #[doc(alias = "IMG_SaveAVIF_IO")]
+    fn save_avif_io(&self, dst: &mut IOStream, quality: i32) -> Result<(), Error> {
+        unsafe {
+            if image::IMG_SaveAVIF_IO(self.raw(), dst.raw(), false, quality.clamp(0, 100)) {
+                Ok(())
+            } else {
+                Err(get_error())
+            }
+        }
+    }
+}
+
 /// Method extensions for creating Textures from a `TextureCreator`
 pub trait LoadTexture {
     fn load_texture<P: AsRef<Path>>(&self, filename: P) -> Result<Texture, Error>;
     fn load_texture_bytes(&self, buf: &[u8]) -> Result<Texture, Error>;
+    fn load_texture_typed(&self, buf: &[u8], type_hint: &str) -> Result<Texture, Error>;
 }
 
 impl<T> LoadTexture for TextureCreator<T> {
@@ -134,6 +199,110 @@ impl<T> LoadTexture for TextureCreator<T> {
             }
         }
     }
+
+    #[doc(alias = "IMG_LoadTyped_IO")]
+    fn load_texture_typed(&self, buf: &[u8], type_hint: &str) -> Result<Texture, Error> {
+        //! Loads an SDL Texture from a buffer, skipping format detection and decoding it as `type_hint` (an extension such as "PNG" or "TGA"). Needed for formats without a reliable header like TGA
+        let stream = IOStream::from_bytes(buf)?;
+        let surface = stream.load_typed(type_hint)?;
+        unsafe {
+            let raw = sys::render::SDL_CreateTextureFromSurface(self.raw(), surface.raw());
+            if raw.is_null() {
+                Err(get_error())
+            } else {
+                Ok(self.raw_create_texture(raw))
+            }
+        }
+    }
+}
+
+/// A single frame of an [`Animation`].
+pub struct AnimationFrame {
+    surface: *mut sys::surface::SDL_Surface,
+    delay: u32,
+}
+
+impl AnimationFrame {
+    /// The image of this frame. It is owned by the `Animation` it came from.
+    pub fn surface(&self) -> &SurfaceRef {
+        unsafe { SurfaceRef::from_ll(self.surface) }
+    }
+
+    /// How long this frame should be displayed, in milliseconds.
+    pub fn delay(&self) -> u32 {
+        self.delay
+    }
+}
+
+/// An animated image, such as an animated GIF or WEBP, decoded into a sequence of surfaces.
+///
+/// # Example
+/// ```no_run
+/// use sdl3::image::Animation;
+///
+/// let animation = Animation::from_file("spinner.gif").unwrap();
+/// for frame in animation.frames() {
+///     println!("{}x{} for {}ms", frame.surface().width(), frame.surface().height(), frame.delay());
+/// }
+/// ```
+pub struct Animation {
+    raw: *mut image::IMG_Animation,
+    frames: Vec<AnimationFrame>,
+}
+
+impl Animation {
+    /// Takes ownership of an `IMG_Animation` returned by SDL_image.
+    ///
+    /// # Safety
+    /// `raw` must be null or a valid animation that is not freed elsewhere.
+    unsafe fn from_ll(raw: *mut image::IMG_Animation) -> Result<Animation, Error> {
+        if raw.is_null() {
+            return Err(get_error());
+        }
+        let count = (*raw).count.max(0) as usize;
+        let frames = (0..count)
+            .map(|i| AnimationFrame {
+                surface: *(*raw).frames.add(i),
+                delay: (*(*raw).delays.add(i)).max(0) as u32,
+            })
+            .collect();
+        Ok(Animation { raw, frames })
+    }
+
+    /// Loads an animation from a file.
+    #[doc(alias = "IMG_LoadAnimation")]
+    pub fn from_file<P: AsRef<Path>>(filename: P) -> Result<Animation, Error> {
+        let c_filename = CString::new(filename.as_ref().to_str().unwrap()).unwrap();
+        unsafe { Self::from_ll(image::IMG_LoadAnimation(c_filename.as_ptr())) }
+    }
+
+    /// Loads an animation from an `IOStream`. The stream is not closed.
+    #[doc(alias = "IMG_LoadAnimation_IO")]
+    pub fn from_io(src: &IOStream) -> Result<Animation, Error> {
+        unsafe { Self::from_ll(image::IMG_LoadAnimation_IO(src.raw(), false)) }
+    }
+
+    /// The width of every frame, in pixels.
+    pub fn width(&self) -> u32 {
+        unsafe { (*self.raw).w as u32 }
+    }
+
+    /// The height of every frame, in pixels.
+    pub fn height(&self) -> u32 {
+        unsafe { (*self.raw).h as u32 }
+    }
+
+    /// The frames of the animation, in display order.
+    pub fn frames(&self) -> &[AnimationFrame] {
+        &self.frames
+    }
+}
+
+impl Drop for Animation {
+    #[doc(alias = "IMG_FreeAnimation")]
+    fn drop(&mut self) {
+        unsafe { image::IMG_FreeAnimation(self.raw) }
+    }
 }
 
 /// Returns the version of the dynamically linked `SDL_image` library
@@ -4,6 +4,7 @@ use libc::c_void;
 use std::ffi::CString;
 use std::io;
 use std::marker::PhantomData;
+use std::mem;
 use std::path::Path;
 use std::ptr::NonNull;
 
@@ -102,6 +103,32 @@ impl<'a> IOStream<'a> {
         }
     }
 
+    /// Creates a read-only stream reading from a Rust `Read + Seek` object,
+    /// like a `File`, a `Cursor` or a zip archive entry.
+    ///
+    /// Unlike [`IOStream::from_read`], nothing is read up front. The stream
+    /// owns `stream` and drops it when closed.
+    #[doc(alias = "SDL_OpenIO")]
+    pub fn from_stream<S>(stream: S) -> Result<IOStream<'static>, Error>
+    where
+        S: io::Read + io::Seek + 'static,
+    {
+        let mut iface: sys::iostream::SDL_IOStreamInterface = unsafe { mem::zeroed() };
+        iface.version = mem::size_of::<sys::iostream::SDL_IOStreamInterface>() as u32;
+        iface.size = Some(stream_size::<S>);
+        iface.seek = Some(stream_seek::<S>);
+        iface.read = Some(stream_read::<S>);
+        iface.close = Some(stream_close::<S>);
+
+        let userdata = Box::into_raw(Box::new(stream));
+        let raw = unsafe { sys::iostream::SDL_OpenIO(&iface, userdata as *mut c_void) };
+        if raw.is_null() {
+            // SDL doesn't call `close` if opening fails.
+            drop(unsafe { Box::from_raw(userdata) });
+        }
+        unsafe { Self::from_ll_or_error(raw) }
+    }
+
     /// Reads a `Read` object into a buffer and then passes it to `IOStream.from_bytes`.
     ///
     /// The buffer must be provided to this function and must live as long as the
@@ -130,6 +157,33 @@ impl<'a> IOStream<'a> {
         }
     }
 
+    /// Creates a writable stream backed by memory that grows as it is
+    /// written to. See [`IOStream::dynamic_memory`] to get the written bytes.
+    #[doc(alias = "SDL_IOFromDynamicMem")]
+    pub fn from_dynamic_mem() -> Result<IOStream<'static>, Error> {
+        unsafe { Self::from_ll_or_error(sys::iostream::SDL_IOFromDynamicMem()) }
+    }
+
+    /// Returns a copy of the bytes written to a stream created with
+    /// [`IOStream::from_dynamic_mem`]. Other streams return an empty `Vec`.
+    pub fn dynamic_memory(&self) -> Vec<u8> {
+        let data = unsafe {
+            let props = sys::iostream::SDL_GetIOProperties(self.raw());
+            sys::properties::SDL_GetPointerProperty(
+                props,
+                sys::iostream::SDL_PROP_IOSTREAM_DYNAMIC_MEMORY_POINTER,
+                std::ptr::null_mut(),
+            )
+        };
+        // The memory is only allocated on the first write.
+        match (data.is_null(), self.len()) {
+            (false, Some(len)) => {
+                unsafe { std::slice::from_raw_parts(data as *const u8, len) }.to_vec()
+            }
+            _ => Vec::new(),
+        }
+    }
+
     /// Gets the stream's total size in bytes.
     ///
     /// Returns `None` if the stream size can't be determined
@@ -161,6 +215,77 @@ impl<'a> IOStream<'a> {
     }
 }
 
+unsafe extern "C" fn stream_size<S: io::Seek>(userdata: *mut c_void) -> i64 {
+    let stream = &mut *(userdata as *mut S);
+    let size = (|| {
+        let position = stream.stream_position()?;
+        let end = stream.seek(io::SeekFrom::End(0))?;
+        stream.seek(io::SeekFrom::Start(position))?;
+        Ok::<_, io::Error>(end)
+    })();
+    match size {
+        Ok(size) => size as i64,
+        Err(e) => {
+            crate::set_error(&e.to_string()).ok();
+            -1
+        }
+    }
+}
+
+unsafe extern "C" fn stream_seek<S: io::Seek>(
+    userdata: *mut c_void,
+    offset: i64,
+    whence: sys::iostream::SDL_IOWhence,
+) -> i64 {
+    let stream = &mut *(userdata as *mut S);
+    let pos = match whence {
+        sys::iostream::SDL_IO_SEEK_SET => io::SeekFrom::Start(offset as u64),
+        sys::iostream::SDL_IO_SEEK_CUR => io::SeekFrom::Current(offset),
+        sys::iostream::SDL_IO_SEEK_END => io::SeekFrom::End(offset),
+        _ => {
+            crate::set_error("Unknown value for 'whence'").ok();
+            return -1;
+        }
+    };
+    match stream.seek(pos) {
+        Ok(position) => position as i64,
+        Err(e) => {
+            crate::set_error(&e.to_string()).ok();
+            -1
+        }
+    }
+}
+
+unsafe extern "C" fn stream_read<S: io::Read>(
+    userdata: *mut c_void,
+    ptr: *mut c_void,
+    size: usize,
+    status: *mut sys::iostream::SDL_IOStatus,
+) -> usize {
+    let stream = &mut *(userdata as *mut S);
+    let buf = std::slice::from_raw_parts_mut(ptr as *mut u8, size);
+    loop {
+        match stream.read(buf) {
+            Ok(0) if size > 0 => {
+                *status = sys::iostream::SDL_IO_STATUS_EOF;
+                return 0;
+            }
+            Ok(read) => return read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => {
+                crate::set_error(&e.to_string()).ok();
+                *status = sys::iostream::SDL_IO_STATUS_ERROR;
+                return 0;
+            }
+        }
+    }
+}
+
+unsafe extern "C" fn stream_close<S>(userdata: *mut c_void) -> bool {
+    drop(Box::from_raw(userdata as *mut S));
+    true
+}
+
 /// See [`SDL_IOStatus`](sys::iostream::SDL_IOStatus)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum IOStatus {
@@ -204,7 +329,11 @@ impl io::Read for IOStream<'_> {
         let out_len = buf.len();
         let ret =
             unsafe { sys::iostream::SDL_ReadIO(self.raw(), buf.as_ptr() as *mut c_void, out_len) };
-        Ok(ret)
+        if ret == 0 && out_len != 0 && self.status() == IOStatus::Error {
+            Err(io::Error::other(get_error()))
+        } else {
+            Ok(ret)
+        }
     }
 }
 
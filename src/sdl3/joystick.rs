@@ -111,6 +111,17 @@ pub struct PowerInfo {
     pub percentage: i32,
 }
 
+impl PowerInfo {
+    /// Battery charge left as a percentage, or `None` if SDL can't tell.
+    pub fn battery_percentage(&self) -> Option<i32> {
+        if self.percentage < 0 {
+            None
+        } else {
+            Some(self.percentage)
+        }
+    }
+}
+
 impl fmt::Debug for PowerInfo {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -180,6 +191,9 @@ impl Joystick {
 
     /// Return true if the joystick has been opened and currently
     /// connected.
+    ///
+    /// This turns false as soon as the device is unplugged, so it can be polled to
+    /// detect a hot-unplug without waiting for the removal event.
     #[doc(alias = "SDL_JoystickConnected")]
     pub fn connected(&self) -> bool {
         unsafe { sys::joystick::SDL_JoystickConnected(self.raw) }
@@ -539,6 +539,21 @@ impl Keycode {
     pub fn to_ll(self) -> SDL_Keycode {
         SDL_Keycode(self as u32)
     }
+
+    /// Returns an iterator over every defined keycode: first the ones for printable
+    /// characters, then the ones derived from scancodes, each in ascending order.
+    ///
+    /// `Unknown` and `ScancodeMask` are skipped, since they don't name a key. Use
+    /// [`Keycode::name`] to label the keys.
+    pub fn all() -> impl Iterator<Item = Keycode> {
+        let characters = 0..=0xff;
+        let from_scancodes = (0..sys::scancode::SDL_SCANCODE_COUNT.0 as u32)
+            .map(|scancode| scancode | SDLK_SCANCODE_MASK.0);
+        characters
+            .chain(from_scancodes)
+            .filter_map(Keycode::from_u32)
+            .filter(|&keycode| keycode != Keycode::Unknown && keycode != Keycode::ScancodeMask)
+    }
 }
 
 use std::fmt;
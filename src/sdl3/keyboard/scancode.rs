@@ -520,6 +520,17 @@ impl Scancode {
     pub fn to_i32(self) -> i32 {
         self as i32
     }
+
+    /// Returns an iterator over every scancode that names a physical key, in
+    /// ascending order.
+    ///
+    /// `Unknown`, `Reserved` and `Count` are skipped, which makes this suitable for
+    /// listing the options of a key-rebinding screen, along with [`Scancode::name`].
+    pub fn all() -> impl Iterator<Item = Scancode> {
+        (0..SDL_SCANCODE_COUNT.0)
+            .filter_map(Scancode::from_i32)
+            .filter(|&scancode| scancode != Scancode::Unknown && scancode != Scancode::Reserved)
+    }
 }
 
 impl From<Scancode> for SDL_Scancode {
@@ -131,6 +131,7 @@ pub mod render;
 mod sdl;
 #[cfg(feature = "hidapi")]
 pub mod sensor;
+pub mod storage;
 pub mod surface;
 pub mod timer;
 pub mod touch;
@@ -1,6 +1,7 @@
 use crate::sys::log::*;
 use std::ffi::{CStr, CString};
 use std::ptr::null_mut;
+use std::sync::Mutex;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Category {
@@ -93,13 +94,12 @@ impl Priority {
     }
 }
 
-fn dummy(_priority: Priority, _category: Category, _message: &str) {}
+type OutputFunction = Box<dyn FnMut(Priority, Category, &str) + Send>;
 
-#[allow(non_upper_case_globals)]
 // NEVER make this public
-static mut custom_log_fn: fn(Priority, Category, &str) = dummy;
+static OUTPUT_FUNCTION: Mutex<Option<OutputFunction>> = Mutex::new(None);
 
-unsafe extern "C" fn rust_sdl2_log_fn(
+unsafe extern "C" fn rust_sdl3_log_fn(
     _userdata: *mut libc::c_void,
     category: libc::c_int,
     priority: SDL_LogPriority,
@@ -108,15 +108,79 @@ unsafe extern "C" fn rust_sdl2_log_fn(
     let category = Category::from_ll(category);
     let priority = Priority::from_ll(priority);
     let message = CStr::from_ptr(message).to_string_lossy();
-    custom_log_fn(priority, category, &message);
+    // Panics can't unwind out of this `extern "C"` function, they abort.
+    let mut output = OUTPUT_FUNCTION.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(output) = output.as_mut() {
+        output(priority, category, &message);
+    }
 }
 
+/// Replaces the function SDL writes log messages with.
+///
+/// The callback can be called from any thread, one message at a time.
+/// Logging from inside the callback deadlocks, and panicking in it aborts the
+/// process.
 #[doc(alias = "SDL_SetLogOutputFunction")]
-pub fn set_output_function(callback: fn(Priority, Category, &str)) {
-    unsafe {
-        custom_log_fn = callback;
-        SDL_SetLogOutputFunction(Some(rust_sdl2_log_fn), null_mut());
+pub fn set_output_function<F>(callback: F)
+where
+    F: FnMut(Priority, Category, &str) + Send + 'static,
+{
+    // SDL holds its log lock while calling `rust_sdl3_log_fn`, which then
+    // takes `OUTPUT_FUNCTION`, so never call into SDL with the guard held.
+    let previous = {
+        let mut output = OUTPUT_FUNCTION.lock().unwrap_or_else(|e| e.into_inner());
+        output.replace(Box::new(callback))
     };
+    drop(previous);
+    unsafe {
+        SDL_SetLogOutputFunction(Some(rust_sdl3_log_fn), null_mut());
+    }
+}
+
+/// Restores the log output function SDL started with, dropping the one set
+/// with [`set_output_function`].
+#[doc(alias = "SDL_GetDefaultLogOutputFunction")]
+pub fn reset_output_function() {
+    unsafe {
+        SDL_SetLogOutputFunction(SDL_GetDefaultLogOutputFunction(), null_mut());
+    }
+    let previous = OUTPUT_FUNCTION
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take();
+    drop(previous);
+}
+
+/// Forwards SDL log messages to the [`log`](https://docs.rs/log) crate, with
+/// a target of `sdl3::<category>`, e.g. `sdl3::video`.
+///
+/// SDL still filters messages by its own priorities first, see
+/// [`set_log_priorities`].
+#[cfg(feature = "log")]
+pub fn forward_to_log_crate() {
+    set_output_function(|priority, category, message| {
+        let level = match priority {
+            Priority::Trace | Priority::Verbose => ::log::Level::Trace,
+            Priority::Debug => ::log::Level::Debug,
+            Priority::Info => ::log::Level::Info,
+            Priority::Warn => ::log::Level::Warn,
+            Priority::Error | Priority::Critical => ::log::Level::Error,
+        };
+        let target = match category {
+            Category::Application => "sdl3::application",
+            Category::Error => "sdl3::error",
+            Category::Assert => "sdl3::assert",
+            Category::System => "sdl3::system",
+            Category::Audio => "sdl3::audio",
+            Category::Video => "sdl3::video",
+            Category::Render => "sdl3::render",
+            Category::Input => "sdl3::input",
+            Category::Test => "sdl3::test",
+            Category::Gpu => "sdl3::gpu",
+            Category::Custom | Category::Unknown => "sdl3::custom",
+        };
+        ::log::log!(target: target, level, "{message}");
+    });
 }
 
 #[doc(alias = "SDL_SetLogPriorities")]
@@ -165,7 +229,7 @@ pub fn log(message: &str) {
     let message = message.replace('%', "%%");
     let message = CString::new(message).unwrap();
     unsafe {
-        SDL_Log(message.into_raw());
+        SDL_Log(message.as_ptr());
     }
 }
 
@@ -175,7 +239,7 @@ pub fn log_trace(category: Category, message: &str) {
     let message = CString::new(message).unwrap();
     let category = category.to_ll();
     unsafe {
-        SDL_LogTrace(category, message.into_raw());
+        SDL_LogTrace(category, message.as_ptr());
     }
 }
 
@@ -185,7 +249,7 @@ pub fn log_verbose(category: Category, message: &str) {
     let message = CString::new(message).unwrap();
     let category = category.to_ll();
     unsafe {
-        SDL_LogVerbose(category, message.into_raw());
+        SDL_LogVerbose(category, message.as_ptr());
     }
 }
 
@@ -195,7 +259,7 @@ pub fn log_debug(category: Category, message: &str) {
     let message = CString::new(message).unwrap();
     let category = category.to_ll();
     unsafe {
-        SDL_LogDebug(category, message.into_raw());
+        SDL_LogDebug(category, message.as_ptr());
     }
 }
 
@@ -205,7 +269,7 @@ pub fn log_info(category: Category, message: &str) {
     let message = CString::new(message).unwrap();
     let category = category.to_ll();
     unsafe {
-        SDL_LogInfo(category, message.into_raw());
+        SDL_LogInfo(category, message.as_ptr());
     }
 }
 
@@ -215,7 +279,7 @@ pub fn log_warn(category: Category, message: &str) {
     let message = CString::new(message).unwrap();
     let category = category.to_ll();
     unsafe {
-        SDL_LogWarn(category, message.into_raw());
+        SDL_LogWarn(category, message.as_ptr());
     }
 }
 
@@ -225,7 +289,7 @@ pub fn log_error(category: Category, message: &str) {
     let message = CString::new(message).unwrap();
     let category = category.to_ll();
     unsafe {
-        SDL_LogError(category, message.into_raw());
+        SDL_LogError(category, message.as_ptr());
     }
 }
 
@@ -235,7 +299,7 @@ pub fn log_critical(category: Category, message: &str) {
     let message = CString::new(message).unwrap();
     let category = category.to_ll();
     unsafe {
-        SDL_LogCritical(category, message.into_raw());
+        SDL_LogCritical(category, message.as_ptr());
     }
 }
 
@@ -246,6 +310,6 @@ pub fn log_message(category: Category, priority: Priority, message: &str) {
     let category = category.to_ll();
     let priority = priority.to_ll();
     unsafe {
-        SDL_LogMessage(category, priority, message.into_raw());
+        SDL_LogMessage(category, priority, message.as_ptr());
     }
 }
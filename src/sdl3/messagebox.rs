@@ -22,6 +22,24 @@ bitflags! {
     }
 }
 
+/// The severity of a message box, which selects its icon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessageBoxLevel {
+    Information,
+    Warning,
+    Error,
+}
+
+impl From<MessageBoxLevel> for MessageBoxFlag {
+    fn from(level: MessageBoxLevel) -> Self {
+        match level {
+            MessageBoxLevel::Information => MessageBoxFlag::INFORMATION,
+            MessageBoxLevel::Warning => MessageBoxFlag::WARNING,
+            MessageBoxLevel::Error => MessageBoxFlag::ERROR,
+        }
+    }
+}
+
 impl From<MessageBoxFlag> for sys::messagebox::SDL_MessageBoxFlags {
     fn from(flags: MessageBoxFlag) -> Self {
         sys::messagebox::SDL_MessageBoxFlags(flags.bits() as sys::stdinc::Uint32)
@@ -57,7 +75,7 @@ impl From<sys::messagebox::SDL_MessageBoxButtonFlags> for MessageBoxButtonFlag {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MessageBoxColorScheme {
     pub background: (u8, u8, u8),
     pub text: (u8, u8, u8),
@@ -171,6 +189,8 @@ impl error::Error for ShowMessageError {
 /// There is no way to know if the user clicked "Ok" or closed the message box,
 /// If you want to retrieve which button was clicked and customize a bit more
 /// your message box, use `show_message_box` instead.
+///
+/// This blocks until the message box is dismissed.
 #[doc(alias = "SDL_ShowSimpleMessageBox")]
 pub fn show_simple_message_box<'a, W>(
     flags: MessageBoxFlag,
@@ -207,6 +227,28 @@ where
     }
 }
 
+/// Show a message box with an "Ok" button and the icon of `level`.
+///
+/// This blocks until the message box is dismissed. It can be used before
+/// SDL is initialized, for instance to report that initialization failed.
+///
+/// ```no_run
+/// use sdl3::messagebox::{show_simple, MessageBoxLevel};
+///
+/// if let Err(e) = sdl3::init() {
+///     show_simple(MessageBoxLevel::Error, "Startup failed", &e.to_string(), None).ok();
+/// }
+/// ```
+#[doc(alias = "SDL_ShowSimpleMessageBox")]
+pub fn show_simple(
+    level: MessageBoxLevel,
+    title: &str,
+    message: &str,
+    parent: Option<&Window>,
+) -> Result<(), ShowMessageError> {
+    show_simple_message_box(level.into(), title, message, parent)
+}
+
 /// Show a customizable message box.
 ///
 /// An array of buttons is required for it to work. The array can be empty,
@@ -216,6 +258,7 @@ where
 /// Note that the variant of the `ClickedButton` enum will also be returned if the message box
 /// has been forcefully closed (Alt-F4, ...)
 ///
+/// This blocks until the message box is dismissed.
 #[doc(alias = "SDL_ShowMessageBox")]
 pub fn show_message_box<'a, 'b, W, M>(
     flags: MessageBoxFlag,
@@ -259,6 +302,10 @@ where
             text: b_text.as_ptr(),
         })
         .collect();
+    // Kept in a local so the pointer in `msg_box_data` stays valid.
+    let raw_scheme = scheme.map(|scheme| sys::messagebox::SDL_MessageBoxColorScheme {
+        colors: From::from(scheme),
+    });
     let result = unsafe {
         let msg_box_data = sys::messagebox::SDL_MessageBoxData {
             flags: flags.into(),
@@ -267,23 +314,16 @@ where
             message: message.as_ptr() as *const c_char,
             numbuttons: raw_buttons.len() as c_int,
             buttons: raw_buttons.as_ptr(),
-            colorScheme: if let Some(scheme) = scheme {
-                &sys::messagebox::SDL_MessageBoxColorScheme {
-                    colors: From::from(scheme),
-                } as *const _
-            } else {
-                ptr::null()
-            },
+            colorScheme: raw_scheme
+                .as_ref()
+                .map_or(ptr::null(), |scheme| scheme as *const _),
         };
         sys::messagebox::SDL_ShowMessageBox(&msg_box_data as *const _, &mut button_id as &mut _)
     };
     if result {
-        match button_id {
-            -1 => Ok(ClickedButton::CloseButton),
-            id => {
-                let button = buttons.iter().find(|b| b.button_id == id);
-                Ok(ClickedButton::CustomButton(button.unwrap()))
-            }
+        match buttons.iter().find(|b| b.button_id == button_id) {
+            Some(button) => Ok(ClickedButton::CustomButton(button)),
+            None => Ok(ClickedButton::CloseButton),
         }
     } else {
         Err(SdlError(get_error()))
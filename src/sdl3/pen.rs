@@ -1,3 +1,11 @@
+//! Pen (stylus) input
+//!
+//! SDL3 has no way to list pens ahead of time: a pen becomes known when it
+//! first comes into proximity, and is identified by the `which` field of the
+//! `Event::Pen*` events. Wrap that id in a [`Pen`] to query it. Likewise,
+//! there is no capability query; a pen supports an axis if it sends
+//! `Event::PenAxis` events for it.
+
 #[non_exhaustive]
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 #[repr(i32)]
@@ -37,3 +45,54 @@ impl PenAxis {
         }
     }
 }
+
+/// The kind of surface a pen draws on.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PenDeviceType {
+    Invalid,
+    Unknown,
+    /// The pen touches the display, like a tablet screen.
+    Direct,
+    /// The pen touches a separate surface, like a graphics tablet.
+    Indirect,
+}
+
+impl PenDeviceType {
+    #[inline]
+    pub fn from_ll(raw: sys::pen::SDL_PenDeviceType) -> PenDeviceType {
+        match raw {
+            sys::pen::SDL_PenDeviceType::UNKNOWN => PenDeviceType::Unknown,
+            sys::pen::SDL_PenDeviceType::DIRECT => PenDeviceType::Direct,
+            sys::pen::SDL_PenDeviceType::INDIRECT => PenDeviceType::Indirect,
+            _ => PenDeviceType::Invalid,
+        }
+    }
+}
+
+/// A pen seen in a pen event.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Pen {
+    id: sys::pen::SDL_PenID,
+}
+
+impl Pen {
+    /// Wrap the `which` field of a pen event.
+    pub fn from_id(which: u32) -> Pen {
+        Pen {
+            id: sys::pen::SDL_PenID(which),
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id.0
+    }
+
+    /// Get the kind of surface this pen draws on.
+    ///
+    /// Returns [`PenDeviceType::Invalid`] once the pen has left proximity
+    /// and been forgotten by SDL.
+    #[doc(alias = "SDL_GetPenDeviceType")]
+    pub fn device_type(&self) -> PenDeviceType {
+        PenDeviceType::from_ll(unsafe { sys::pen::SDL_GetPenDeviceType(self.id) })
+    }
+}
@@ -51,24 +51,52 @@ impl Palette {
     /// Creates a palette from the provided colors
     #[doc(alias = "SDL_SetPaletteColors")]
     pub fn with_colors(colors: &[Color]) -> Result<Self, Error> {
-        let pal = Self::new(colors.len())?;
-
-        // Already validated, so don't check again
-        let ncolors = colors.len() as ::libc::c_int;
-
-        let result = unsafe {
-            let mut raw_colors: Vec<pixels::SDL_Color> =
-                colors.iter().map(|color| color.raw()).collect();
-
-            let pal_ptr = (&mut raw_colors[0]) as *mut pixels::SDL_Color;
+        let mut pal = Self::new(colors.len())?;
+        pal.set_colors(colors, 0)?;
+        Ok(pal)
+    }
 
-            pixels::SDL_SetPaletteColors(pal.raw, pal_ptr, 0, ncolors)
+    /// Replaces the colors of the palette starting at index `first`.
+    ///
+    /// Returns an error, leaving the palette untouched, if the colors would go
+    /// past the end of the palette.
+    #[doc(alias = "SDL_SetPaletteColors")]
+    pub fn set_colors(&mut self, colors: &[Color], first: usize) -> Result<(), Error> {
+        // SDL writes what fits and then fails without setting an error.
+        if !matches!(first.checked_add(colors.len()), Some(end) if end <= self.len()) {
+            return Err(Error(format!(
+                "{} colors starting at index {first} don't fit in a palette of {} colors",
+                colors.len(),
+                self.len()
+            )));
+        }
+        let (Ok(first), Ok(ncolors)) = (c_int::try_from(first), c_int::try_from(colors.len()))
+        else {
+            return Err(Error("palette index out of range".to_owned()));
         };
+        let raw_colors: Vec<pixels::SDL_Color> = colors.iter().map(|color| color.raw()).collect();
+
+        let result =
+            unsafe { pixels::SDL_SetPaletteColors(self.raw, raw_colors.as_ptr(), first, ncolors) };
 
         if !result {
             Err(get_error())
         } else {
-            Ok(pal)
+            Ok(())
+        }
+    }
+
+    /// Returns a copy of the colors in the palette.
+    pub fn colors(&self) -> Vec<Color> {
+        unsafe {
+            let raw = &*self.raw;
+            if raw.colors.is_null() {
+                return Vec::new();
+            }
+            std::slice::from_raw_parts(raw.colors, raw.ncolors as usize)
+                .iter()
+                .map(|&color| color.into())
+                .collect()
         }
     }
 
@@ -101,6 +129,50 @@ fn create_palette() {
     assert!(palette.len() == 255);
 }
 
+#[test]
+fn palette_set_colors() {
+    let mut palette = Palette::new(4).unwrap();
+    palette
+        .set_colors(&[Color::RED, Color::GREEN, Color::BLUE], 1)
+        .unwrap();
+    assert_eq!(
+        palette.colors(),
+        [Color::WHITE, Color::RED, Color::GREEN, Color::BLUE]
+    );
+
+    // Overflowing the palette is an error and changes nothing.
+    assert!(palette
+        .set_colors(&[Color::BLACK, Color::BLACK], 3)
+        .is_err());
+    assert!(palette.set_colors(&[Color::BLACK], 4).is_err());
+    assert!(palette.set_colors(&[Color::BLACK], usize::MAX).is_err());
+    assert_eq!(
+        palette.colors(),
+        [Color::WHITE, Color::RED, Color::GREEN, Color::BLUE]
+    );
+}
+
+#[test]
+fn indexed_surface_uses_palette() {
+    use crate::surface::Surface;
+
+    let colors: Vec<_> = (0..=255).map(|u| Color::RGB(u, 255 - u, u / 2)).collect();
+    let palette = Palette::with_colors(&colors).unwrap();
+    assert_eq!(palette.len(), 256);
+
+    let mut surface = Surface::new(4, 4, PixelFormat::INDEX8).unwrap();
+    surface.set_palette(&palette).unwrap();
+    surface.with_lock_mut(|pixels| pixels[0] = 200);
+
+    let converted = surface.convert_format(PixelFormat::RGBA8888).unwrap();
+    let pixel = converted
+        .with_lock(|pixels| u32::from_ne_bytes([pixels[0], pixels[1], pixels[2], pixels[3]]));
+    assert_eq!(
+        Color::from_u32(&PixelFormat::RGBA8888, pixel),
+        Color::RGB(200, 55, 100)
+    );
+}
+
 #[test]
 fn pixel_format_is_alpha() {
     assert!(PixelFormat::RGBA8888.is_alpha());
@@ -120,6 +192,29 @@ fn pixel_format_details_basic() {
     assert_eq!(det.bytes_per_pixel, 3);
 }
 
+#[test]
+fn pixel_format_masks_round_trip() {
+    let masks = PixelFormat::RGBA8888.into_masks().unwrap();
+    assert_eq!(
+        masks,
+        PixelMasks {
+            bpp: 32,
+            rmask: 0xff000000,
+            gmask: 0x00ff0000,
+            bmask: 0x0000ff00,
+            amask: 0x000000ff,
+        }
+    );
+    assert_eq!(PixelFormat::from_masks(masks), PixelFormat::RGBA8888);
+
+    let det = PixelFormat::RGBA8888.details();
+    assert_eq!(
+        (det.r_mask, det.g_mask, det.b_mask, det.a_mask),
+        (masks.rmask, masks.gmask, masks.bmask, masks.amask)
+    );
+    assert_eq!((det.r_shift, det.a_shift), (24, 0));
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
 #[repr(C)]
 pub struct Color {
@@ -188,6 +283,112 @@ impl Color {
         (self.r, self.g, self.b, self.a)
     }
 
+    /// Linearly interpolates every channel, including alpha, from `self` at
+    /// `t = 0` to `other` at `t = 1`. `t` is clamped to that range.
+    pub fn lerp(self, other: Color, t: f32) -> Color {
+        let t = t.clamp(0., 1.);
+        let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Color::RGBA(
+            channel(self.r, other.r),
+            channel(self.g, other.g),
+            channel(self.b, other.b),
+            channel(self.a, other.a),
+        )
+    }
+
+    /// Multiplies the color channels by alpha, as expected by premultiplied
+    /// alpha blending.
+    pub fn premultiply(self) -> Color {
+        let channel = |c: u8| ((c as u16 * self.a as u16 + 127) / 255) as u8;
+        Color::RGBA(channel(self.r), channel(self.g), channel(self.b), self.a)
+    }
+
+    /// Undoes [`Color::premultiply`]. Precision lost to premultiplying can't
+    /// be recovered, and a fully transparent color becomes transparent black.
+    pub fn unpremultiply(self) -> Color {
+        if self.a == 0 {
+            return Color::RGBA(0, 0, 0, 0);
+        }
+        let a = self.a as u16;
+        let channel = |c: u8| ((c as u16 * 255 + a / 2) / a).min(255) as u8;
+        Color::RGBA(channel(self.r), channel(self.g), channel(self.b), self.a)
+    }
+
+    /// Creates an opaque color from hue (in degrees), saturation and value
+    /// (both from 0 to 1).
+    pub fn from_hsv(h: f32, s: f32, v: f32) -> Color {
+        let s = s.clamp(0., 1.);
+        let v = v.clamp(0., 1.);
+        let chroma = v * s;
+        Color::from_hue_chroma(h, chroma, v - chroma)
+    }
+
+    /// Returns the hue (in degrees, from 0 to 360), saturation and value
+    /// (both from 0 to 1) of this color, ignoring alpha.
+    pub fn to_hsv(self) -> (f32, f32, f32) {
+        let (hue, max, min) = self.hue_max_min();
+        let saturation = if max == 0. { 0. } else { (max - min) / max };
+        (hue, saturation, max)
+    }
+
+    /// Creates an opaque color from hue (in degrees), saturation and
+    /// lightness (both from 0 to 1).
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Color {
+        let s = s.clamp(0., 1.);
+        let l = l.clamp(0., 1.);
+        let chroma = (1. - (2. * l - 1.).abs()) * s;
+        Color::from_hue_chroma(h, chroma, l - chroma / 2.)
+    }
+
+    /// Returns the hue (in degrees, from 0 to 360), saturation and lightness
+    /// (both from 0 to 1) of this color, ignoring alpha.
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let (hue, max, min) = self.hue_max_min();
+        let lightness = (max + min) / 2.;
+        let saturation = if max == min {
+            0.
+        } else {
+            (max - min) / (1. - (2. * lightness - 1.).abs())
+        };
+        (hue, saturation, lightness)
+    }
+
+    fn from_hue_chroma(h: f32, chroma: f32, m: f32) -> Color {
+        let h = h.rem_euclid(360.) / 60.;
+        let x = chroma * (1. - (h % 2. - 1.).abs());
+        let (r, g, b) = match h as u32 {
+            0 => (chroma, x, 0.),
+            1 => (x, chroma, 0.),
+            2 => (0., chroma, x),
+            3 => (0., x, chroma),
+            4 => (x, 0., chroma),
+            _ => (chroma, 0., x),
+        };
+        let channel = |c: f32| ((c + m) * 255.).round().clamp(0., 255.) as u8;
+        Color::RGB(channel(r), channel(g), channel(b))
+    }
+
+    /// Returns the hue in degrees and the largest and smallest channel from
+    /// 0 to 1.
+    fn hue_max_min(self) -> (f32, f32, f32) {
+        let r = self.r as f32 / 255.;
+        let g = self.g as f32 / 255.;
+        let b = self.b as f32 / 255.;
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+        let hue = if delta == 0. {
+            0.
+        } else if max == r {
+            60. * ((g - b) / delta).rem_euclid(6.)
+        } else if max == g {
+            60. * ((b - r) / delta + 2.)
+        } else {
+            60. * ((r - g) / delta + 4.)
+        };
+        (hue, max, min)
+    }
+
     // Implemented manually and kept private, because reasons
     #[inline]
     const fn raw(self) -> pixels::SDL_Color {
@@ -211,6 +412,89 @@ impl Color {
     pub const CYAN: Color = Color::RGBA(0, 255, 255, 255);
 }
 
+#[cfg(test)]
+fn assert_close(actual: (f32, f32, f32), expected: (f32, f32, f32)) {
+    let close = |a: f32, b: f32| (a - b).abs() < 0.01;
+    assert!(
+        close(actual.0, expected.0) && close(actual.1, expected.1) && close(actual.2, expected.2),
+        "{actual:?} != {expected:?}"
+    );
+}
+
+#[test]
+fn color_hsv_primaries() {
+    assert_close(Color::RED.to_hsv(), (0., 1., 1.));
+    assert_close(Color::GREEN.to_hsv(), (120., 1., 1.));
+    assert_close(Color::BLUE.to_hsv(), (240., 1., 1.));
+    assert_close(Color::BLACK.to_hsv(), (0., 0., 0.));
+
+    assert_eq!(Color::from_hsv(0., 1., 1.), Color::RED);
+    assert_eq!(Color::from_hsv(120., 1., 1.), Color::GREEN);
+    assert_eq!(Color::from_hsv(240., 1., 1.), Color::BLUE);
+    assert_eq!(Color::from_hsv(360., 1., 1.), Color::RED);
+    assert_eq!(Color::from_hsv(0., 0., 1.), Color::WHITE);
+}
+
+#[test]
+fn color_hsl_primaries() {
+    assert_close(Color::RED.to_hsl(), (0., 1., 0.5));
+    assert_close(Color::GREEN.to_hsl(), (120., 1., 0.5));
+    assert_close(Color::WHITE.to_hsl(), (0., 0., 1.));
+
+    assert_eq!(Color::from_hsl(0., 1., 0.5), Color::RED);
+    assert_eq!(Color::from_hsl(120., 1., 0.5), Color::GREEN);
+    assert_eq!(Color::from_hsl(-120., 1., 0.5), Color::BLUE);
+}
+
+#[test]
+fn color_hsv_hsl_round_trip() {
+    for color in [
+        Color::RGB(12, 200, 99),
+        Color::RGB(250, 128, 3),
+        Color::RGB(77, 77, 200),
+        Color::GRAY,
+    ] {
+        let (h, s, v) = color.to_hsv();
+        assert_eq!(Color::from_hsv(h, s, v), color);
+        let (h, s, l) = color.to_hsl();
+        assert_eq!(Color::from_hsl(h, s, l), color);
+    }
+}
+
+#[test]
+fn color_lerp() {
+    let from = Color::RGBA(0, 100, 255, 0);
+    let to = Color::RGBA(255, 200, 0, 255);
+    assert_eq!(from.lerp(to, 0.), from);
+    assert_eq!(from.lerp(to, 1.), to);
+    assert_eq!(from.lerp(to, 0.5), Color::RGBA(128, 150, 128, 128));
+    assert_eq!(from.lerp(to, -1.), from);
+    assert_eq!(from.lerp(to, 2.), to);
+}
+
+#[test]
+fn color_premultiply_round_trip() {
+    let color = Color::RGBA(200, 100, 50, 128);
+    let premultiplied = color.premultiply();
+    assert_eq!(premultiplied, Color::RGBA(100, 50, 25, 128));
+
+    let restored = premultiplied.unpremultiply();
+    assert_eq!(restored.a, color.a);
+    for (a, b) in [
+        (restored.r, color.r),
+        (restored.g, color.g),
+        (restored.b, color.b),
+    ] {
+        assert!(a.abs_diff(b) <= 1, "{restored:?} != {color:?}");
+    }
+
+    assert_eq!(Color::RED.premultiply(), Color::RED);
+    assert_eq!(
+        Color::RGBA(10, 20, 30, 0).premultiply().unpremultiply(),
+        Color::RGBA(0, 0, 0, 0)
+    );
+}
+
 impl From<Color> for pixels::SDL_Color {
     fn from(val: Color) -> Self {
         val.raw()
@@ -340,6 +624,9 @@ impl From<FColor> for Color {
     }
 }
 
+/// A pixel format described by its channel masks, the way many image
+/// libraries describe their formats.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct PixelMasks {
     /// Bits per pixel; usually 15, 16, or 32
     pub bpp: u8,
@@ -534,27 +821,12 @@ impl PixelFormat {
                 !ptr.is_null(),
                 "SDL_GetPixelFormatDetails returned null for format: {self:?}"
             );
-            let d = *ptr;
-            PixelFormatDetails {
-                format: PixelFormat::from_ll(d.format),
-                bits_per_pixel: d.bits_per_pixel,
-                bytes_per_pixel: d.bytes_per_pixel,
-                r_mask: d.Rmask,
-                g_mask: d.Gmask,
-                b_mask: d.Bmask,
-                a_mask: d.Amask,
-                r_bits: d.Rbits,
-                g_bits: d.Gbits,
-                b_bits: d.Bbits,
-                a_bits: d.Abits,
-                r_shift: d.Rshift,
-                g_shift: d.Gshift,
-                b_shift: d.Bshift,
-                a_shift: d.Ashift,
-            }
+            PixelFormatDetails::from_ll(ptr)
         }
     }
 
+    /// Finds the pixel format matching the given masks, or
+    /// [`PixelFormat::UNKNOWN`] if there is none.
     #[doc(alias = "SDL_GetPixelFormatForMasks")]
     pub fn from_masks(masks: PixelMasks) -> PixelFormat {
         unsafe {
@@ -568,6 +840,8 @@ impl PixelFormat {
         }
     }
 
+    /// Returns the masks describing this format. Fails for formats that can't
+    /// be described by masks, like FourCC formats.
     #[doc(alias = "SDL_GetMasksForPixelFormat")]
     pub fn into_masks(self) -> Result<PixelMasks, Error> {
         let mut bpp = 0;
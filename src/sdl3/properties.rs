@@ -41,26 +41,38 @@ unsafe extern "C" fn enumerate(
     props: SDL_PropertiesID,
     name: *const c_char,
 ) {
-    let properties: &Properties = std::mem::transmute(&props);
+    let properties = Properties::const_from_ll(props);
     let callback_ptr = userdata as *mut EnumerateCallback;
     let name = CStr::from_ptr(name);
     match name.to_str() {
-        Ok(name) => (*callback_ptr)(properties, Ok(name)),
-        Err(error) => (*callback_ptr)(properties, Err(PropertiesError::StringError(error))),
+        Ok(name) => (*callback_ptr)(&properties, Ok(name)),
+        Err(error) => (*callback_ptr)(&properties, Err(PropertiesError::StringError(error))),
     }
 }
 
+unsafe extern "C" fn collect_name(
+    userdata: *mut c_void,
+    _props: SDL_PropertiesID,
+    name: *const c_char,
+) {
+    let names = &mut *(userdata as *mut Vec<String>);
+    names.push(CStr::from_ptr(name).to_string_lossy().into_owned());
+}
+
+#[deprecated(
+    since = "0.18.5",
+    note = "Boxed values are now freed without a cleanup function."
+)]
 pub type CleanupBox = fn(*mut c_void);
-unsafe extern "C" fn cleanup_box(userdata: *mut c_void, value: *mut c_void) {
-    let callback_ptr = userdata as *mut CleanupBox;
-    (*callback_ptr)(value);
+
+unsafe extern "C" fn cleanup_box<T>(_userdata: *mut c_void, value: *mut c_void) {
+    drop(Box::from_raw(value as *mut T));
 }
 
-pub type CleanupCallback = Box<dyn FnOnce(*mut c_void)>;
-unsafe extern "C" fn cleanup_custom(userdata: *mut c_void, value: *mut c_void) {
-    let callback_ptr = userdata as *mut CleanupCallback;
-    let callback = Box::from_raw(callback_ptr);
-    (*callback)(value);
+pub type CleanupCallback<T> = Box<dyn FnOnce(*mut T)>;
+unsafe extern "C" fn cleanup_custom<T>(userdata: *mut c_void, value: *mut c_void) {
+    let callback = Box::from_raw(userdata as *mut CleanupCallback<T>);
+    (*callback)(value as *mut T);
 }
 
 pub use sys::properties::SDL_PropertyType as PropertyType;
@@ -153,6 +165,7 @@ impl Properties {
         }
     }
 
+    /// Get the global property set, which lives until SDL quits.
     #[doc(alias = "SDL_GetGlobalProperties")]
     pub fn global() -> Result<Self, PropertiesError> {
         let internal = unsafe { sys::properties::SDL_GetGlobalProperties() };
@@ -194,14 +207,24 @@ impl Properties {
         }
     }
 
+    /// Set a pointer property, calling `cleanup` with the pointer once the
+    /// property is overwritten, cleared, or the set is destroyed.
+    ///
+    /// If the property can't be set, `cleanup` is called right away.
     #[doc(alias = "SDL_SetPointerPropertyWithCleanup")]
     pub fn set_with_cleanup<T>(
         &self,
         name: &str,
         value: *mut T,
-        cleanup: Box<dyn FnOnce(*mut T)>,
+        cleanup: CleanupCallback<T>,
     ) -> Result<(), PropertiesError> {
-        cstring!(name);
+        let name = match CString::new(name) {
+            Ok(name) => name,
+            Err(error) => {
+                cleanup(value);
+                return Err(PropertiesError::ArgumentError(error));
+            }
+        };
         let value_ptr = value as *mut c_void;
         let cleanup_ptr = Box::into_raw(Box::new(cleanup)) as *mut c_void;
         if unsafe {
@@ -209,7 +232,7 @@ impl Properties {
                 self.internal,
                 name.as_ptr(),
                 value_ptr,
-                Some(cleanup_custom),
+                Some(cleanup_custom::<T>),
                 cleanup_ptr,
             )
         } {
@@ -259,8 +282,10 @@ impl Properties {
     }
 
     #[doc(alias = "SDL_EnumerateProperties")]
-    pub fn enumerate(&self, callback: EnumerateCallback) -> Result<(), PropertiesError> {
-        let callback_ptr = Box::into_raw(Box::new(callback)) as *mut c_void;
+    pub fn enumerate(&self, mut callback: EnumerateCallback) -> Result<(), PropertiesError> {
+        // The callback is only called during the enumeration, so it can stay
+        // on the stack.
+        let callback_ptr = &mut callback as *mut EnumerateCallback as *mut c_void;
         if unsafe {
             sys::properties::SDL_EnumerateProperties(self.internal, Some(enumerate), callback_ptr)
         } {
@@ -270,6 +295,25 @@ impl Properties {
         }
     }
 
+    /// Get the names of all the properties in this set.
+    ///
+    /// Names that aren't valid UTF-8 are converted lossily.
+    #[doc(alias = "SDL_EnumerateProperties")]
+    pub fn names(&self) -> Result<Vec<String>, PropertiesError> {
+        let mut names: Vec<String> = Vec::new();
+        if unsafe {
+            sys::properties::SDL_EnumerateProperties(
+                self.internal,
+                Some(collect_name),
+                &mut names as *mut Vec<String> as *mut c_void,
+            )
+        } {
+            Ok(names)
+        } else {
+            Err(PropertiesError::SdlError(get_error()))
+        }
+    }
+
     #[doc(alias = "SDL_ClearProperty")]
     pub fn clear(&mut self, name: &str) -> Result<(), PropertiesError> {
         cstring!(name);
@@ -368,25 +412,21 @@ impl<T> Setter<*mut T> for Properties {
     }
 }
 
-impl<T> Setter<Box<T>> for Properties {
+/// The box is owned by the property set from then on, and dropped when the
+/// property is overwritten, cleared, or the set is destroyed. That can happen
+/// long after this call, so `T` must not borrow anything.
+impl<T: 'static> Setter<Box<T>> for Properties {
     #[doc(alias = "SDL_SetPointerPropertyWithCleanup")]
     fn set(&self, name: &str, value: Box<T>) -> Result<(), PropertiesError> {
         cstring!(name);
         let value_ptr: *mut c_void = Box::into_raw(value) as *mut c_void;
-        let cleanup: CleanupBox = |value: *mut c_void| {
-            let value = value as *mut T;
-            unsafe {
-                drop(Box::from_raw(value));
-            }
-        };
-        let cleanup_ptr = Box::into_raw(Box::new(cleanup)) as *mut c_void;
         if unsafe {
             sys::properties::SDL_SetPointerPropertyWithCleanup(
                 self.internal,
                 name.as_ptr(),
                 value_ptr,
-                Some(cleanup_box),
-                cleanup_ptr,
+                Some(cleanup_box::<T>),
+                ptr::null_mut(),
             )
         } {
             Ok(())
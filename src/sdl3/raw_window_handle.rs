@@ -1,16 +1,82 @@
 extern crate raw_window_handle;
 
 use self::raw_window_handle::{
-    DisplayHandle, HandleError, HasDisplayHandle, HasWindowHandle, RawWindowHandle, WindowHandle,
+    DisplayHandle, HandleError, HasDisplayHandle, HasWindowHandle, RawWindowHandle,
+    WindowHandle as BorrowedWindowHandle,
 };
 use crate::video::Window;
 use raw_window_handle::RawDisplayHandle;
 use std::{ffi::CStr, num::NonZero, ptr::NonNull};
 use sys::properties::{SDL_GetNumberProperty, SDL_GetPointerProperty};
 
+/// A `Send + Sync` view of a [`Window`] that implements [`HasWindowHandle`] and
+/// [`HasDisplayHandle`].
+///
+/// Graphics libraries such as `wgpu` require their surface target to be
+/// `Send + Sync`, which `Window` is not. `WindowHandle` only exposes the raw
+/// handles, so it can be passed to those libraries without writing the unsafe
+/// wrapper yourself. On X11, Wayland and Win32 no extra window setup is
+/// needed; on macOS the window must be built with
+/// [`WindowBuilder::metal_view`](crate::video::WindowBuilder::metal_view).
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sdl3::raw_window_handle::WindowHandle;
+///
+/// let sdl_context = sdl3::init()?;
+/// let video_subsystem = sdl_context.video()?;
+/// let window = video_subsystem
+///     .window("wgpu", 800, 600)
+///     .metal_view() // only required on macOS, ignored elsewhere
+///     .build()?;
+///
+/// let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::new_without_display_handle_from_env());
+/// let surface = instance.create_surface(WindowHandle::new(&window))?;
+/// # drop(surface);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy)]
+pub struct WindowHandle<'a> {
+    window: &'a Window,
+}
+
+impl<'a> WindowHandle<'a> {
+    /// Wraps a borrowed window.
+    pub fn new(window: &'a Window) -> Self {
+        WindowHandle { window }
+    }
+}
+
+impl<'a> From<&'a Window> for WindowHandle<'a> {
+    fn from(window: &'a Window) -> Self {
+        WindowHandle::new(window)
+    }
+}
+
+// SAFETY: the wrapped `Window` is private and never handed out; the only
+// thing reachable through this type is the pair of raw window and display
+// handles. Those are plain identifiers, and it is up to the consumer of the
+// handles to use them from the correct thread, as documented by
+// `raw-window-handle`.
+unsafe impl Send for WindowHandle<'_> {}
+unsafe impl Sync for WindowHandle<'_> {}
+
+impl HasWindowHandle for WindowHandle<'_> {
+    fn window_handle(&self) -> Result<BorrowedWindowHandle<'_>, HandleError> {
+        self.window.window_handle()
+    }
+}
+
+impl HasDisplayHandle for WindowHandle<'_> {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        self.window.display_handle()
+    }
+}
+
 // Access window handle using SDL3 properties
 impl HasWindowHandle for Window {
-    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+    fn window_handle(&self) -> Result<BorrowedWindowHandle<'_>, HandleError> {
         // Windows
         #[cfg(target_os = "windows")]
         unsafe {
@@ -28,11 +94,14 @@ impl HasWindowHandle for Window {
                 sys::video::SDL_PROP_WINDOW_WIN32_INSTANCE_POINTER,
                 std::ptr::null_mut(),
             );
-            let mut handle = Win32WindowHandle::new(NonZero::new_unchecked(hwnd.addr() as isize));
-            handle.hinstance = Some(NonZero::new_unchecked(hinstance.addr() as isize));
+            let Some(hwnd) = NonZero::new(hwnd.addr() as isize) else {
+                return Err(HandleError::Unavailable);
+            };
+            let mut handle = Win32WindowHandle::new(hwnd);
+            handle.hinstance = NonZero::new(hinstance.addr() as isize);
             let raw_window_handle = RawWindowHandle::Win32(handle);
 
-            Ok(WindowHandle::borrow_raw(raw_window_handle))
+            Ok(BorrowedWindowHandle::borrow_raw(raw_window_handle))
         }
 
         // macOS
@@ -55,7 +124,7 @@ impl HasWindowHandle for Window {
             let handle = AppKitWindowHandle::new(NonNull::new_unchecked(ns_view.cast()));
             let raw_window_handle = RawWindowHandle::AppKit(handle);
 
-            Ok(WindowHandle::borrow_raw(raw_window_handle))
+            Ok(BorrowedWindowHandle::borrow_raw(raw_window_handle))
         }
 
         // iOS
@@ -73,7 +142,7 @@ impl HasWindowHandle for Window {
             let handle = UiKitWindowHandle::new(NonNull::new_unchecked(ui_view));
             let raw_window_handle = RawWindowHandle::UiKit(handle);
 
-            Ok(WindowHandle::borrow_raw(raw_window_handle))
+            Ok(BorrowedWindowHandle::borrow_raw(raw_window_handle))
         }
 
         // Android
@@ -91,7 +160,7 @@ impl HasWindowHandle for Window {
             let handle = AndroidNdkWindowHandle::new(NonNull::new_unchecked(native_window));
             let raw_window_handle = RawWindowHandle::AndroidNdk(handle);
 
-            Ok(WindowHandle::borrow_raw(raw_window_handle))
+            Ok(BorrowedWindowHandle::borrow_raw(raw_window_handle))
         }
 
         // Linux (X11 or Wayland)
@@ -115,10 +184,13 @@ impl HasWindowHandle for Window {
                         sys::video::SDL_PROP_WINDOW_X11_WINDOW_NUMBER,
                         0,
                     );
+                    if window == 0 {
+                        return Err(HandleError::Unavailable);
+                    }
                     let handle = XlibWindowHandle::new(window as u64);
                     let raw_window_handle = RawWindowHandle::Xlib(handle);
 
-                    Ok(WindowHandle::borrow_raw(raw_window_handle))
+                    Ok(BorrowedWindowHandle::borrow_raw(raw_window_handle))
                 }
                 b"wayland" => {
                     use self::raw_window_handle::WaylandWindowHandle;
@@ -130,14 +202,16 @@ impl HasWindowHandle for Window {
                         sys::video::SDL_PROP_WINDOW_WAYLAND_SURFACE_POINTER,
                         std::ptr::null_mut(),
                     );
-                    let handle = WaylandWindowHandle::new(NonNull::new_unchecked(window));
+                    let Some(window) = NonNull::new(window) else {
+                        return Err(HandleError::Unavailable);
+                    };
+                    let handle = WaylandWindowHandle::new(window);
                     let raw_window_handle = RawWindowHandle::Wayland(handle);
 
-                    Ok(WindowHandle::borrow_raw(raw_window_handle))
-                }
-                _ => {
-                    panic!("{video_driver:?} video driver is not supported, please file an issue with raw-window-handle.",);
+                    Ok(BorrowedWindowHandle::borrow_raw(raw_window_handle))
                 }
+                // Drivers such as "offscreen" or "dummy" have no native window.
+                _ => Err(HandleError::NotSupported),
             }
         }
     }
@@ -237,9 +311,7 @@ impl HasDisplayHandle for Window {
 
                     Ok(DisplayHandle::borrow_raw(raw_window_handle))
                 }
-                _ => {
-                    panic!("{video_driver:?} video driver is not supported, please file an issue with raw-window-handle.");
-                }
+                _ => Err(HandleError::NotSupported),
             }
         }
     }
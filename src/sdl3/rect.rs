@@ -922,6 +922,30 @@ mod test {
         );
     }
 
+    #[test]
+    fn enclose_points_scattered() {
+        let points = [
+            Point::new(3, -2),
+            Point::new(-4, 7),
+            Point::new(10, 1),
+            Point::new(0, 0),
+        ];
+        assert_eq!(
+            Rect::from_enclose_points(&points, None),
+            Some(Rect::new(-4, -2, 15, 10))
+        );
+        // only the points inside the clip rect count
+        assert_eq!(
+            Rect::from_enclose_points(&points, Rect::new(-1, -5, 12, 8)),
+            Some(Rect::new(0, -2, 11, 4))
+        );
+    }
+
+    #[test]
+    fn enclose_points_empty() {
+        assert_eq!(Rect::from_enclose_points(&[], None), None);
+    }
+
     #[test]
     fn enclose_points_outside_clip_rect() {
         assert_eq!(
@@ -979,6 +1003,41 @@ mod test {
         );
     }
 
+    #[test]
+    fn intersection_adjacent_and_contained() {
+        let rect = Rect::new(0, 0, 10, 10);
+        // sharing an edge isn't overlapping
+        assert_eq!(rect.intersection(Rect::new(10, 0, 5, 10)), None);
+        assert_eq!(rect.intersection(Rect::new(0, 10, 10, 5)), None);
+        // fully inside
+        assert_eq!(
+            rect.intersection(Rect::new(2, 3, 4, 5)),
+            Some(Rect::new(2, 3, 4, 5))
+        );
+    }
+
+    #[test]
+    fn union_adjacent_and_disjoint() {
+        let rect = Rect::new(0, 0, 10, 10);
+        assert_eq!(rect.union(Rect::new(10, 0, 5, 10)), Rect::new(0, 0, 15, 10));
+        assert_eq!(
+            rect.union(Rect::new(-20, 30, 5, 5)),
+            Rect::new(-20, 0, 30, 35)
+        );
+        assert_eq!(rect.union(Rect::new(2, 2, 2, 2)), rect);
+    }
+
+    #[test]
+    fn contains_point_edges() {
+        let rect = Rect::new(0, 0, 10, 10);
+        assert!(rect.contains_point(Point::new(0, 0)));
+        assert!(rect.contains_point(Point::new(9, 9)));
+        // right and bottom edges are exclusive
+        assert!(!rect.contains_point(Point::new(10, 5)));
+        assert!(!rect.contains_point(Point::new(5, 10)));
+        assert!(!rect.contains_point(Point::new(-1, 5)));
+    }
+
     #[test]
     fn intersect_line() {
         assert_eq!(
@@ -987,6 +1046,26 @@ mod test {
         );
     }
 
+    #[test]
+    fn intersect_line_inside_and_outside() {
+        let rect = Rect::new(0, 0, 10, 10);
+        // fully inside: unchanged
+        assert_eq!(
+            rect.intersect_line(Point::new(2, 2), Point::new(7, 3)),
+            Some((Point::new(2, 2), Point::new(7, 3)))
+        );
+        // horizontal line clipped to the rect
+        assert_eq!(
+            rect.intersect_line(Point::new(-5, 4), Point::new(20, 4)),
+            Some((Point::new(0, 4), Point::new(9, 4)))
+        );
+        // passes beside the rect
+        assert_eq!(
+            rect.intersect_line(Point::new(-5, 20), Point::new(20, 20)),
+            None
+        );
+    }
+
     #[test]
     fn clamp_size_zero() {
         assert_eq!(tuple(0, 0, 1, 1), Rect::new(0, 0, 0, 0).into());
@@ -43,6 +43,7 @@ use std::convert::{Into, TryFrom, TryInto};
 use std::error;
 use std::ffi::{c_char, CStr, CString};
 use std::fmt;
+use std::hash::{Hash, Hasher};
 #[cfg(not(feature = "unsafe_textures"))]
 use std::marker::PhantomData;
 use std::mem;
@@ -117,6 +118,8 @@ impl From<i64> for TextureAccess {
 }
 
 // floating-point point
+// `repr(C)` so slices can be passed to SDL as `SDL_FPoint` arrays without copying
+#[repr(C)]
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub struct FPoint {
     pub x: f32,
@@ -159,6 +162,8 @@ impl From<(u32, u32)> for FPoint {
 }
 
 // floating-point rectangle
+// `repr(C)` so slices can be passed to SDL as `SDL_FRect` arrays without copying
+#[repr(C)]
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub struct FRect {
     pub x: f32,
@@ -194,6 +199,107 @@ impl FRect {
         self.x = update.x;
         self.y = update.y;
     }
+
+    pub fn from_ll(raw: sys::rect::SDL_FRect) -> FRect {
+        FRect::new(raw.x, raw.y, raw.w, raw.h)
+    }
+
+    /// Determines whether two rectangles intersect.
+    ///
+    /// Unlike [`Rect::has_intersection`](crate::rect::Rect::has_intersection),
+    /// rectangles that only share an edge are considered to intersect, since
+    /// SDL compares float edges without an epsilon.
+    #[doc(alias = "SDL_HasRectIntersectionFloat")]
+    pub fn has_intersection(&self, other: FRect) -> bool {
+        unsafe { sys::rect::SDL_HasRectIntersectionFloat(&self.to_ll(), &other.to_ll()) }
+    }
+
+    /// Calculates the intersection of two rectangles, or `None` if they
+    /// don't intersect. Rectangles that only share an edge give a rectangle
+    /// with a width or height of zero.
+    #[doc(alias = "SDL_GetRectIntersectionFloat")]
+    pub fn intersection(&self, other: FRect) -> Option<FRect> {
+        let mut out = FRect::new(0., 0., 0., 0.).to_ll();
+        let success = unsafe {
+            sys::rect::SDL_GetRectIntersectionFloat(&self.to_ll(), &other.to_ll(), &mut out)
+        };
+        if success {
+            Some(FRect::from_ll(out))
+        } else {
+            None
+        }
+    }
+
+    /// Calculates the smallest rectangle containing both rectangles. Empty
+    /// rectangles are ignored.
+    #[doc(alias = "SDL_GetRectUnionFloat")]
+    pub fn union(&self, other: FRect) -> FRect {
+        let mut out = FRect::new(0., 0., 0., 0.).to_ll();
+        unsafe { sys::rect::SDL_GetRectUnionFloat(&self.to_ll(), &other.to_ll(), &mut out) };
+        FRect::from_ll(out)
+    }
+
+    /// Calculate a minimal rectangle enclosing a set of points.
+    /// If a clipping rectangle is given, only points that are within it will be
+    /// considered.
+    #[doc(alias = "SDL_GetRectEnclosingPointsFloat")]
+    pub fn from_enclose_points<R>(points: &[FPoint], clipping_rect: R) -> Option<FRect>
+    where
+        R: Into<Option<FRect>>,
+    {
+        if points.is_empty() {
+            return None;
+        }
+
+        let raw_points: Vec<sys::rect::SDL_FPoint> = points.iter().map(FPoint::to_ll).collect();
+        let clipping_rect = clipping_rect.into().map(|rect| rect.to_ll());
+        let clip_ptr = match clipping_rect.as_ref() {
+            Some(r) => r as *const _,
+            None => ptr::null(),
+        };
+        let mut out = FRect::new(0., 0., 0., 0.).to_ll();
+
+        let result = unsafe {
+            sys::rect::SDL_GetRectEnclosingPointsFloat(
+                raw_points.as_ptr(),
+                raw_points.len() as c_int,
+                clip_ptr,
+                &mut out,
+            )
+        };
+
+        if result {
+            Some(FRect::from_ll(out))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the smallest integer rectangle containing this one.
+    pub fn to_rect(&self) -> Rect {
+        let left = self.x.floor();
+        let top = self.y.floor();
+        let right = (self.x + self.w).ceil();
+        let bottom = (self.y + self.h).ceil();
+        Rect::new(
+            left as i32,
+            top as i32,
+            (right - left) as u32,
+            (bottom - top) as u32,
+        )
+    }
+}
+
+impl From<sys::rect::SDL_FRect> for FRect {
+    fn from(raw: sys::rect::SDL_FRect) -> Self {
+        FRect::from_ll(raw)
+    }
+}
+
+impl From<FRect> for sys::rect::SDL_FRect {
+    fn from(rect: FRect) -> Self {
+        rect.to_ll()
+    }
 }
 
 impl From<Rect> for FRect {
@@ -251,36 +357,90 @@ pub struct RendererInfo {
 }
 
 /// Blend mode for `Canvas`, `Texture` or `Surface`.
-#[repr(i32)]
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum BlendMode {
     /// no blending (replace destination with source).
-    None = sys::blendmode::SDL_BLENDMODE_NONE.0 as i32,
+    None,
     /// Alpha blending
     ///
     /// dstRGB = (srcRGB * srcA) + (dstRGB * (1-srcA))
     ///
     /// dstA = srcA + (dstA * (1-srcA))
-    Blend = sys::blendmode::SDL_BLENDMODE_BLEND.0 as i32,
+    Blend,
     /// Additive blending
     ///
     /// dstRGB = (srcRGB * srcA) + dstRGB
     ///
     /// dstA = dstA (keep original alpha)
-    Add = sys::blendmode::SDL_BLENDMODE_ADD.0 as i32,
+    Add,
     /// Color modulate
     ///
     /// dstRGB = srcRGB * dstRGB
-    Mod = sys::blendmode::SDL_BLENDMODE_MOD.0 as i32,
+    Mod,
     /// Color multiply
-    Mul = sys::blendmode::SDL_BLENDMODE_MUL.0 as i32,
+    Mul,
     /// Invalid blending mode (indicates error)
-    Invalid = sys::blendmode::SDL_BLENDMODE_INVALID.0 as i32,
+    Invalid,
+    /// A blend mode created with [`BlendMode::custom`], or any other blend mode
+    /// SDL reports that has no named variant.
+    Custom(u32),
+}
+
+impl BlendMode {
+    /// Composes a custom blend mode.
+    ///
+    /// The color channels are computed as
+    /// `dstRGB = src_color_factor * srcRGB <color_operation> dst_color_factor * dstRGB`
+    /// and the alpha channel as
+    /// `dstA = src_alpha_factor * srcA <alpha_operation> dst_alpha_factor * dstA`.
+    ///
+    /// Not every renderer supports every combination of factors and operations.
+    #[doc(alias = "SDL_ComposeCustomBlendMode")]
+    pub fn custom(
+        src_color_factor: BlendFactor,
+        dst_color_factor: BlendFactor,
+        color_operation: BlendOperation,
+        src_alpha_factor: BlendFactor,
+        dst_alpha_factor: BlendFactor,
+        alpha_operation: BlendOperation,
+    ) -> BlendMode {
+        let mode = unsafe {
+            sys::blendmode::SDL_ComposeCustomBlendMode(
+                src_color_factor.into(),
+                dst_color_factor.into(),
+                color_operation.into(),
+                src_alpha_factor.into(),
+                dst_alpha_factor.into(),
+                alpha_operation.into(),
+            )
+        };
+        BlendMode::from_ll(mode)
+    }
+
+    fn from_ll(mode: SDL_BlendMode) -> BlendMode {
+        match mode {
+            x if x == sys::blendmode::SDL_BLENDMODE_NONE => BlendMode::None,
+            x if x == sys::blendmode::SDL_BLENDMODE_BLEND => BlendMode::Blend,
+            x if x == sys::blendmode::SDL_BLENDMODE_ADD => BlendMode::Add,
+            x if x == sys::blendmode::SDL_BLENDMODE_MOD => BlendMode::Mod,
+            x if x == sys::blendmode::SDL_BLENDMODE_MUL => BlendMode::Mul,
+            x if x == sys::blendmode::SDL_BLENDMODE_INVALID => BlendMode::Invalid,
+            x => BlendMode::Custom(x.0),
+        }
+    }
 }
 
 impl From<BlendMode> for SDL_BlendMode {
     fn from(value: BlendMode) -> Self {
-        SDL_BlendMode(value as u32)
+        match value {
+            BlendMode::None => sys::blendmode::SDL_BLENDMODE_NONE,
+            BlendMode::Blend => sys::blendmode::SDL_BLENDMODE_BLEND,
+            BlendMode::Add => sys::blendmode::SDL_BLENDMODE_ADD,
+            BlendMode::Mod => sys::blendmode::SDL_BLENDMODE_MOD,
+            BlendMode::Mul => sys::blendmode::SDL_BLENDMODE_MUL,
+            BlendMode::Invalid => sys::blendmode::SDL_BLENDMODE_INVALID,
+            BlendMode::Custom(mode) => SDL_BlendMode(mode),
+        }
     }
 }
 
@@ -288,17 +448,63 @@ impl TryFrom<SDL_BlendMode> for BlendMode {
     type Error = ();
 
     fn try_from(n: SDL_BlendMode) -> Result<Self, Self::Error> {
-        use self::BlendMode::*;
+        Ok(BlendMode::from_ll(n))
+    }
+}
 
-        Ok(match n {
-            x if x == sys::blendmode::SDL_BLENDMODE_NONE => None,
-            x if x == sys::blendmode::SDL_BLENDMODE_BLEND => Blend,
-            x if x == sys::blendmode::SDL_BLENDMODE_ADD => Add,
-            x if x == sys::blendmode::SDL_BLENDMODE_MOD => Mod,
-            x if x == sys::blendmode::SDL_BLENDMODE_MUL => Mul,
-            x if x == sys::blendmode::SDL_BLENDMODE_INVALID => Invalid,
-            _ => return Err(()),
-        })
+/// The normalized factor used to multiply pixel components in a custom
+/// [`BlendMode`].
+#[repr(i32)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum BlendFactor {
+    /// 0, 0, 0, 0
+    Zero = sys::blendmode::SDL_BLENDFACTOR_ZERO.0 as i32,
+    /// 1, 1, 1, 1
+    One = sys::blendmode::SDL_BLENDFACTOR_ONE.0 as i32,
+    /// srcR, srcG, srcB, srcA
+    SrcColor = sys::blendmode::SDL_BLENDFACTOR_SRC_COLOR.0 as i32,
+    /// 1-srcR, 1-srcG, 1-srcB, 1-srcA
+    OneMinusSrcColor = sys::blendmode::SDL_BLENDFACTOR_ONE_MINUS_SRC_COLOR.0 as i32,
+    /// srcA, srcA, srcA, srcA
+    SrcAlpha = sys::blendmode::SDL_BLENDFACTOR_SRC_ALPHA.0 as i32,
+    /// 1-srcA, 1-srcA, 1-srcA, 1-srcA
+    OneMinusSrcAlpha = sys::blendmode::SDL_BLENDFACTOR_ONE_MINUS_SRC_ALPHA.0 as i32,
+    /// dstR, dstG, dstB, dstA
+    DstColor = sys::blendmode::SDL_BLENDFACTOR_DST_COLOR.0 as i32,
+    /// 1-dstR, 1-dstG, 1-dstB, 1-dstA
+    OneMinusDstColor = sys::blendmode::SDL_BLENDFACTOR_ONE_MINUS_DST_COLOR.0 as i32,
+    /// dstA, dstA, dstA, dstA
+    DstAlpha = sys::blendmode::SDL_BLENDFACTOR_DST_ALPHA.0 as i32,
+    /// 1-dstA, 1-dstA, 1-dstA, 1-dstA
+    OneMinusDstAlpha = sys::blendmode::SDL_BLENDFACTOR_ONE_MINUS_DST_ALPHA.0 as i32,
+}
+
+impl From<BlendFactor> for sys::blendmode::SDL_BlendFactor {
+    fn from(value: BlendFactor) -> Self {
+        sys::blendmode::SDL_BlendFactor(value as _)
+    }
+}
+
+/// The operation used to combine source and destination pixel components in a
+/// custom [`BlendMode`].
+#[repr(i32)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum BlendOperation {
+    /// dst + src: supported by all renderers
+    Add = sys::blendmode::SDL_BLENDOPERATION_ADD.0 as i32,
+    /// src - dst: supported by D3D, OpenGL, OpenGLES, and Vulkan
+    Subtract = sys::blendmode::SDL_BLENDOPERATION_SUBTRACT.0 as i32,
+    /// dst - src: supported by D3D, OpenGL, OpenGLES, and Vulkan
+    RevSubtract = sys::blendmode::SDL_BLENDOPERATION_REV_SUBTRACT.0 as i32,
+    /// min(dst, src): supported by D3D, OpenGL, OpenGLES, and Vulkan
+    Minimum = sys::blendmode::SDL_BLENDOPERATION_MINIMUM.0 as i32,
+    /// max(dst, src): supported by D3D, OpenGL, OpenGLES, and Vulkan
+    Maximum = sys::blendmode::SDL_BLENDOPERATION_MAXIMUM.0 as i32,
+}
+
+impl From<BlendOperation> for sys::blendmode::SDL_BlendOperation {
+    fn from(value: BlendOperation) -> Self {
+        sys::blendmode::SDL_BlendOperation(value as _)
     }
 }
 
@@ -507,7 +713,7 @@ pub type SurfaceCanvas<'s> = Canvas<Surface<'s>>;
 impl<'s> Canvas<Surface<'s>> {
     /// Creates a 2D software rendering context for a surface.
     ///
-    /// This method should only fail if SDL2 is not built with rendering
+    /// This method should only fail if SDL3 is not built with rendering
     /// support, or there's an out-of-memory error.
     #[doc(alias = "SDL_CreateSoftwareRenderer")]
     pub fn from_surface(surface: Surface<'s>) -> Result<Self, Error> {
@@ -931,6 +1137,9 @@ pub enum ScaleMode {
     Nearest = sdl3_sys::everything::SDL_ScaleMode::NEAREST.0,
     /// linear filtering. this is the default
     Linear = sdl3_sys::everything::SDL_ScaleMode::LINEAR.0,
+    /// nearest pixel sampling with improved scaling for pixel art, available
+    /// since SDL 3.4.0.
+    PixelArt = sdl3_sys::everything::SDL_ScaleMode::PIXELART.0,
 }
 
 impl From<ScaleMode> for sdl3_sys::everything::SDL_ScaleMode {
@@ -938,6 +1147,7 @@ impl From<ScaleMode> for sdl3_sys::everything::SDL_ScaleMode {
         match val {
             ScaleMode::Nearest => sdl3_sys::everything::SDL_ScaleMode::NEAREST,
             ScaleMode::Linear => sdl3_sys::everything::SDL_ScaleMode::LINEAR,
+            ScaleMode::PixelArt => sdl3_sys::everything::SDL_ScaleMode::PIXELART,
         }
     }
 }
@@ -949,11 +1159,66 @@ impl TryFrom<sdl3_sys::everything::SDL_ScaleMode> for ScaleMode {
         Ok(match n {
             sdl3_sys::everything::SDL_ScaleMode::NEAREST => Self::Nearest,
             sdl3_sys::everything::SDL_ScaleMode::LINEAR => Self::Linear,
+            sdl3_sys::everything::SDL_ScaleMode::PIXELART => Self::PixelArt,
             _ => return Err(()),
         })
     }
 }
 
+/// How often a [`Canvas`] waits for the display's vertical refresh when presenting.
+///
+/// SDL stores the setting as a single integer, so `EveryNRefresh(0)`,
+/// `EveryNRefresh(1)` and `EveryNRefresh(-1)` are the same setting as
+/// `Disabled`, `Enabled` and `Adaptive`. They compare equal, and
+/// [`Canvas::vsync`] reports them as the named variants.
+#[derive(Copy, Clone, Debug)]
+pub enum VSync {
+    /// Present immediately, without waiting.
+    Disabled,
+    /// Wait for every vertical refresh.
+    Enabled,
+    /// Wait for the vertical refresh, unless the frame was late.
+    Adaptive,
+    /// Present on every Nth vertical refresh.
+    EveryNRefresh(i32),
+}
+
+impl PartialEq for VSync {
+    fn eq(&self, other: &Self) -> bool {
+        i32::from(*self) == i32::from(*other)
+    }
+}
+
+impl Eq for VSync {}
+
+impl Hash for VSync {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        i32::from(*self).hash(state)
+    }
+}
+
+impl From<VSync> for i32 {
+    fn from(val: VSync) -> Self {
+        match val {
+            VSync::Disabled => 0,
+            VSync::Enabled => 1,
+            VSync::Adaptive => sys::render::SDL_RENDERER_VSYNC_ADAPTIVE,
+            VSync::EveryNRefresh(n) => n,
+        }
+    }
+}
+
+impl From<i32> for VSync {
+    fn from(n: i32) -> Self {
+        match n {
+            0 => VSync::Disabled,
+            1 => VSync::Enabled,
+            sys::render::SDL_RENDERER_VSYNC_ADAPTIVE => VSync::Adaptive,
+            n => VSync::EveryNRefresh(n),
+        }
+    }
+}
+
 /// Texture-creating methods for the renderer
 #[cfg_attr(not(feature = "unsafe_textures"), allow(elided_lifetimes_in_paths))]
 impl<T> TextureCreator<T> {
@@ -1233,13 +1498,17 @@ impl<T: RenderTarget> Canvas<T> {
     }
 
     /// Sets the blend mode used for drawing operations (Fill and Line).
+    ///
+    /// Returns an error if the renderer doesn't support `blend`, which is
+    /// common for [`BlendMode::Custom`] modes.
     #[doc(alias = "SDL_SetRenderDrawBlendMode")]
-    pub fn set_blend_mode(&mut self, blend: BlendMode) {
+    pub fn set_blend_mode(&mut self, blend: BlendMode) -> Result<(), Error> {
         let ret =
             unsafe { sys::render::SDL_SetRenderDrawBlendMode(self.context.raw, blend.into()) };
-        // Should only fail on an invalid renderer
         if !ret {
-            panic!("{}", get_error())
+            Err(get_error())
+        } else {
+            Ok(())
         }
     }
 
@@ -1340,6 +1609,10 @@ impl<T: RenderTarget> Canvas<T> {
     }
 
     /// Sets the drawing area for rendering on the current target.
+    ///
+    /// Drawing coordinates become relative to the viewport's top-left corner and
+    /// nothing is drawn outside of it, which is useful for split-screen or
+    /// minimap rendering. Passing `None` resets the viewport to the whole target.
     #[doc(alias = "SDL_SetRenderViewport")]
     pub fn set_viewport<R: Into<Option<Rect>>>(&mut self, rect: R) {
         let rect = rect.into();
@@ -1430,6 +1703,30 @@ impl<T: RenderTarget> Canvas<T> {
         (scale_x, scale_y)
     }
 
+    /// Sets how presenting is synchronized with the display's refresh.
+    ///
+    /// Returns an error if the renderer doesn't support the requested mode;
+    /// [`VSync::Adaptive`] and [`VSync::EveryNRefresh`] are not available everywhere.
+    #[doc(alias = "SDL_SetRenderVSync")]
+    pub fn set_vsync(&mut self, vsync: VSync) -> Result<(), Error> {
+        if unsafe { sys::render::SDL_SetRenderVSync(self.context.raw, vsync.into()) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Gets how presenting is synchronized with the display's refresh.
+    #[doc(alias = "SDL_GetRenderVSync")]
+    pub fn vsync(&self) -> Result<VSync, Error> {
+        let mut vsync = 0;
+        if unsafe { sys::render::SDL_GetRenderVSync(self.context.raw, &mut vsync) } {
+            Ok(vsync.into())
+        } else {
+            Err(get_error())
+        }
+    }
+
     /// Draws a point on the current rendering target.
     /// Errors if drawing fails for any reason (e.g. driver failure)
     #[doc(alias = "SDL_RenderPoint")]
@@ -1515,11 +1812,7 @@ impl<T: RenderTarget> Canvas<T> {
         let result = unsafe {
             sys::render::SDL_RenderLines(
                 self.context.raw,
-                points
-                    .iter()
-                    .map(|p| p.to_ll())
-                    .collect::<Vec<_>>()
-                    .as_ptr(),
+                points.as_ptr() as *const sys::rect::SDL_FPoint,
                 points.len() as c_int,
             )
         };
@@ -1551,7 +1844,7 @@ impl<T: RenderTarget> Canvas<T> {
         let result = unsafe {
             sys::render::SDL_RenderRects(
                 self.context.raw,
-                rects.iter().map(|r| r.to_ll()).collect::<Vec<_>>().as_ptr(),
+                rects.as_ptr() as *const sys::rect::SDL_FRect,
                 rects.len() as c_int,
             )
         };
@@ -1590,7 +1883,7 @@ impl<T: RenderTarget> Canvas<T> {
         let result = unsafe {
             sys::render::SDL_RenderFillRects(
                 self.context.raw,
-                rects.iter().map(|r| r.to_ll()).collect::<Vec<_>>().as_ptr(),
+                rects.as_ptr() as *const sys::rect::SDL_FRect,
                 rects.len() as c_int,
             )
         };
@@ -2487,12 +2780,63 @@ impl InternalTexture {
         }
     }
 
+    #[doc(alias = "SDL_SetTextureColorModFloat")]
+    pub fn set_color_mod_float(&mut self, red: f32, green: f32, blue: f32) -> Result<(), Error> {
+        let ret = unsafe { sys::render::SDL_SetTextureColorModFloat(self.raw, red, green, blue) };
+
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    #[doc(alias = "SDL_GetTextureColorModFloat")]
+    pub fn color_mod_float(&self) -> (f32, f32, f32) {
+        let (mut r, mut g, mut b) = (0.0, 0.0, 0.0);
+        let ret =
+            unsafe { sys::render::SDL_GetTextureColorModFloat(self.raw, &mut r, &mut g, &mut b) };
+
+        // Should only fail on an invalid texture
+        if !ret {
+            panic!("{}", get_error())
+        } else {
+            (r, g, b)
+        }
+    }
+
+    #[doc(alias = "SDL_SetTextureAlphaModFloat")]
+    pub fn set_alpha_mod_float(&mut self, alpha: f32) -> Result<(), Error> {
+        let ret = unsafe { sys::render::SDL_SetTextureAlphaModFloat(self.raw, alpha) };
+
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    #[doc(alias = "SDL_GetTextureAlphaModFloat")]
+    pub fn alpha_mod_float(&self) -> f32 {
+        let mut alpha = 0.0;
+        let ret = unsafe { sys::render::SDL_GetTextureAlphaModFloat(self.raw, &mut alpha) };
+
+        // Should only fail on an invalid texture
+        if !ret {
+            panic!("{}", get_error())
+        } else {
+            alpha
+        }
+    }
+
     #[doc(alias = "SDL_SetTextureBlendMode")]
-    pub fn set_blend_mode(&mut self, blend: BlendMode) {
+    pub fn set_blend_mode(&mut self, blend: BlendMode) -> Result<(), Error> {
         let ret = unsafe { sys::render::SDL_SetTextureBlendMode(self.raw, blend.into()) };
 
         if !ret {
-            panic!("Error setting blend: {}", get_error())
+            Err(get_error())
+        } else {
+            Ok(())
         }
     }
 
@@ -2511,11 +2855,13 @@ impl InternalTexture {
     }
 
     #[doc(alias = "SDL_SetTextureScaleMode")]
-    pub fn set_scale_mode(&mut self, scale: ScaleMode) {
+    pub fn set_scale_mode(&mut self, scale: ScaleMode) -> Result<(), Error> {
         let ret = unsafe { sdl3_sys::everything::SDL_SetTextureScaleMode(self.raw, scale.into()) };
 
-        if !ret {
-            panic!("Error setting scale mode: {}", get_error())
+        if ret {
+            Ok(())
+        } else {
+            Err(get_error())
         }
     }
 
@@ -2878,9 +3224,38 @@ impl Texture<'_> {
         InternalTexture { raw: self.raw }.alpha_mod()
     }
 
-    /// Sets the blend mode used for drawing operations (Fill and Line).
+    /// Sets an additional color value multiplied into render copy operations,
+    /// with each component in the range `0.0..=1.0`.
+    #[inline]
+    pub fn set_color_mod_float(&mut self, red: f32, green: f32, blue: f32) -> Result<(), Error> {
+        InternalTexture { raw: self.raw }.set_color_mod_float(red, green, blue)
+    }
+
+    /// Gets the additional color value multiplied into render copy operations.
     #[inline]
-    pub fn set_blend_mode(&mut self, blend: BlendMode) {
+    pub fn color_mod_float(&self) -> (f32, f32, f32) {
+        InternalTexture { raw: self.raw }.color_mod_float()
+    }
+
+    /// Sets an additional alpha value multiplied into render copy operations,
+    /// in the range `0.0..=1.0`.
+    #[inline]
+    pub fn set_alpha_mod_float(&mut self, alpha: f32) -> Result<(), Error> {
+        InternalTexture { raw: self.raw }.set_alpha_mod_float(alpha)
+    }
+
+    /// Gets the additional alpha value multiplied into render copy operations.
+    #[inline]
+    pub fn alpha_mod_float(&self) -> f32 {
+        InternalTexture { raw: self.raw }.alpha_mod_float()
+    }
+
+    /// Sets the blend mode used for texture copy operations.
+    ///
+    /// Returns an error if the renderer doesn't support `blend`, which is
+    /// common for [`BlendMode::Custom`] modes.
+    #[inline]
+    pub fn set_blend_mode(&mut self, blend: BlendMode) -> Result<(), Error> {
         InternalTexture { raw: self.raw }.set_blend_mode(blend)
     }
 
@@ -2891,8 +3266,11 @@ impl Texture<'_> {
     }
 
     /// Sets the scale mode for use when rendered.
+    ///
+    /// Use [`ScaleMode::Nearest`] or [`ScaleMode::PixelArt`] to keep pixel art
+    /// sharp when the texture is scaled.
     #[inline]
-    pub fn set_scale_mode(&mut self, scale: ScaleMode) {
+    pub fn set_scale_mode(&mut self, scale: ScaleMode) -> Result<(), Error> {
         InternalTexture { raw: self.raw }.set_scale_mode(scale)
     }
 
@@ -3113,9 +3491,38 @@ impl Texture {
         InternalTexture { raw: self.raw }.alpha_mod()
     }
 
-    /// Sets the blend mode used for drawing operations (Fill and Line).
+    /// Sets an additional color value multiplied into render copy operations,
+    /// with each component in the range `0.0..=1.0`.
     #[inline]
-    pub fn set_blend_mode(&mut self, blend: BlendMode) {
+    pub fn set_color_mod_float(&mut self, red: f32, green: f32, blue: f32) -> Result<(), Error> {
+        InternalTexture { raw: self.raw }.set_color_mod_float(red, green, blue)
+    }
+
+    /// Gets the additional color value multiplied into render copy operations.
+    #[inline]
+    pub fn color_mod_float(&self) -> (f32, f32, f32) {
+        InternalTexture { raw: self.raw }.color_mod_float()
+    }
+
+    /// Sets an additional alpha value multiplied into render copy operations,
+    /// in the range `0.0..=1.0`.
+    #[inline]
+    pub fn set_alpha_mod_float(&mut self, alpha: f32) -> Result<(), Error> {
+        InternalTexture { raw: self.raw }.set_alpha_mod_float(alpha)
+    }
+
+    /// Gets the additional alpha value multiplied into render copy operations.
+    #[inline]
+    pub fn alpha_mod_float(&self) -> f32 {
+        InternalTexture { raw: self.raw }.alpha_mod_float()
+    }
+
+    /// Sets the blend mode used for texture copy operations.
+    ///
+    /// Returns an error if the renderer doesn't support `blend`, which is
+    /// common for [`BlendMode::Custom`] modes.
+    #[inline]
+    pub fn set_blend_mode(&mut self, blend: BlendMode) -> Result<(), Error> {
         InternalTexture { raw: self.raw }.set_blend_mode(blend)
     }
 
@@ -3126,8 +3533,11 @@ impl Texture {
     }
 
     /// Sets the scale mode for use when rendered.
+    ///
+    /// Use [`ScaleMode::Nearest`] or [`ScaleMode::PixelArt`] to keep pixel art
+    /// sharp when the texture is scaled.
     #[inline]
-    pub fn set_scale_mode(&mut self, scale: ScaleMode) {
+    pub fn set_scale_mode(&mut self, scale: ScaleMode) -> Result<(), Error> {
         InternalTexture { raw: self.raw }.set_scale_mode(scale)
     }
 
@@ -3224,6 +3634,163 @@ impl Texture {
     }
 }
 
+/// How the frames of a [`SpriteSheet`] are laid out in its texture.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SpriteFrames {
+    /// Frames of equal size laid out left to right, top to bottom, starting at
+    /// the top-left corner of the texture.
+    Grid {
+        tile_width: u32,
+        tile_height: u32,
+        columns: u32,
+        count: usize,
+    },
+    /// An explicit list of source rectangles, for packed atlases.
+    List(Vec<Rect>),
+}
+
+/// A texture holding several sprites, together with the location of each frame.
+///
+/// ```no_run
+/// # use sdl3::render::{Canvas, FRect, SpriteSheet, Texture};
+/// # use sdl3::video::Window;
+/// # fn draw(canvas: &mut Canvas<Window>, texture: Texture) -> Result<(), sdl3::Error> {
+/// // a 4x4 sheet of 32x32 tiles
+/// let sheet = SpriteSheet::grid(texture, 32, 32, 4, 16);
+/// sheet.draw(canvas, 5, FRect::new(100.0, 100.0, 64.0, 64.0))?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct SpriteSheet<T> {
+    texture: T,
+    frames: SpriteFrames,
+}
+
+impl<T> SpriteSheet<T> {
+    /// Creates a sprite sheet of `count` frames of `tile_width` by `tile_height`
+    /// pixels, laid out in rows of `columns` frames.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `columns` is zero.
+    pub fn grid(texture: T, tile_width: u32, tile_height: u32, columns: u32, count: usize) -> Self {
+        assert!(columns > 0, "a sprite sheet grid needs at least one column");
+        SpriteSheet {
+            texture,
+            frames: SpriteFrames::Grid {
+                tile_width,
+                tile_height,
+                columns,
+                count,
+            },
+        }
+    }
+
+    /// Creates a sprite sheet from an explicit list of frame rectangles.
+    pub fn from_frames(texture: T, frames: Vec<Rect>) -> Self {
+        SpriteSheet {
+            texture,
+            frames: SpriteFrames::List(frames),
+        }
+    }
+
+    /// Returns the number of frames in the sheet.
+    pub fn len(&self) -> usize {
+        match &self.frames {
+            SpriteFrames::Grid { count, .. } => *count,
+            SpriteFrames::List(frames) => frames.len(),
+        }
+    }
+
+    /// Returns `true` if the sheet has no frames.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the rectangle of the texture holding frame `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn src_rect(&self, index: usize) -> Rect {
+        assert!(
+            index < self.len(),
+            "sprite index {index} out of bounds for a sheet of {} frames",
+            self.len()
+        );
+        match &self.frames {
+            SpriteFrames::Grid {
+                tile_width,
+                tile_height,
+                columns,
+                ..
+            } => {
+                let column = (index % *columns as usize) as u32;
+                let row = (index / *columns as usize) as u32;
+                Rect::new(
+                    (column * tile_width) as i32,
+                    (row * tile_height) as i32,
+                    *tile_width,
+                    *tile_height,
+                )
+            }
+            SpriteFrames::List(frames) => frames[index],
+        }
+    }
+
+    /// Returns the frame layout of the sheet.
+    pub fn frames(&self) -> &SpriteFrames {
+        &self.frames
+    }
+
+    /// Returns the underlying texture.
+    pub fn texture(&self) -> &T {
+        &self.texture
+    }
+
+    /// Returns the underlying texture mutably, e.g. to change its color mod.
+    pub fn texture_mut(&mut self) -> &mut T {
+        &mut self.texture
+    }
+
+    /// Consumes the sheet and returns the underlying texture.
+    pub fn into_texture(self) -> T {
+        self.texture
+    }
+}
+
+#[cfg(not(feature = "unsafe_textures"))]
+impl SpriteSheet<Texture<'_>> {
+    /// Copies frame `index` of the sheet to `dst` on the canvas.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn draw<T, R>(&self, canvas: &mut Canvas<T>, index: usize, dst: R) -> Result<(), Error>
+    where
+        T: RenderTarget,
+        R: Into<Option<FRect>>,
+    {
+        canvas.copy(&self.texture, FRect::from(self.src_rect(index)), dst)
+    }
+}
+
+#[cfg(feature = "unsafe_textures")]
+impl SpriteSheet<Texture> {
+    /// Copies frame `index` of the sheet to `dst` on the canvas.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn draw<T, R>(&self, canvas: &mut Canvas<T>, index: usize, dst: R) -> Result<(), Error>
+    where
+        T: RenderTarget,
+        R: Into<Option<FRect>>,
+    {
+        canvas.copy(&self.texture, FRect::from(self.src_rect(index)), dst)
+    }
+}
+
 #[derive(Copy, Clone)]
 pub struct DriverIterator {
     length: i32,
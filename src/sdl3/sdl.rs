@@ -12,6 +12,7 @@ use sys::init::{
 };
 
 use crate::sys;
+use crate::video::{Window, WindowBuildError};
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Error(pub(crate) String);
@@ -164,6 +165,51 @@ impl Sdl {
         EventPump::new(self)
     }
 
+    /// Initializes the video subsystem, opens a centered window and obtains the
+    /// event pump, which is all most programs need to get started.
+    ///
+    /// The window is not resizable and has no graphics API flags set; use
+    /// [`VideoSubsystem::window`] directly for anything else. Like
+    /// [`Sdl::event_pump`], this fails if an `EventPump` is already alive.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use sdl3::event::Event;
+    ///
+    /// let sdl_context = sdl3::init().unwrap();
+    /// let (_video, window, mut event_pump) = sdl_context.bootstrap("My game", 800, 600).unwrap();
+    ///
+    /// let mut canvas = window.into_canvas();
+    /// 'running: loop {
+    ///     for event in event_pump.poll_iter() {
+    ///         if let Event::Quit { .. } = event {
+    ///             break 'running;
+    ///         }
+    ///     }
+    ///     canvas.clear();
+    ///     canvas.present();
+    /// }
+    /// ```
+    pub fn bootstrap(
+        &self,
+        title: &str,
+        width: u32,
+        height: u32,
+    ) -> Result<(VideoSubsystem, Window, EventPump), Error> {
+        let video = self.video()?;
+        let window = video
+            .window(title, width, height)
+            .position_centered()
+            .build()
+            .map_err(|e| match e {
+                WindowBuildError::SdlError(e) => e,
+                e => Error(e.to_string()),
+            })?;
+        let event_pump = self.event_pump()?;
+        Ok((video, window, event_pump))
+    }
+
     #[inline]
     #[doc(hidden)]
     pub fn sdldrop(&self) -> SdlDrop {
@@ -27,7 +27,7 @@ use std::ffi::{c_int, CStr};
 use sys::sensor::{SDL_GetSensorData, SDL_Sensor, SDL_SensorType};
 use sys::stdinc::SDL_free;
 
-type SensorId = sys::sensor::SDL_SensorID;
+pub type SensorId = sys::sensor::SDL_SensorID;
 
 impl SensorSubsystem {
     /// Get a list of currently connected sensors.
@@ -48,6 +48,25 @@ impl SensorSubsystem {
         }
     }
 
+    /// Get the id, name and type of every currently connected sensor.
+    ///
+    /// This includes sensors built into the device, like the accelerometer
+    /// of a phone, but not those attached to a gamepad.
+    #[doc(alias = "SDL_GetSensors")]
+    pub fn sensors(&self) -> Result<Vec<SensorInfo>, Error> {
+        Ok(self
+            .num_sensors()?
+            .into_iter()
+            .map(|id| SensorInfo {
+                id,
+                name: c_str_to_string(unsafe { sys::sensor::SDL_GetSensorNameForID(id) }),
+                sensor_type: SensorType::from_ll(
+                    unsafe { sys::sensor::SDL_GetSensorTypeForID(id) }.0,
+                ),
+            })
+            .collect())
+    }
+
     /// Attempt to open the sensor at index `sensor_id` and return it.
     #[doc(alias = "SDL_OpenSensor")]
     pub fn open(&self, sensor_id: SensorId) -> Result<Sensor, IntegerOrSdlError> {
@@ -73,6 +92,14 @@ impl SensorSubsystem {
     }
 }
 
+/// Information about a connected sensor, available without opening it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SensorInfo {
+    pub id: SensorId,
+    pub name: String,
+    pub sensor_type: SensorType,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SensorType {
     Unknown,
@@ -188,6 +215,22 @@ impl Sensor {
             })
         }
     }
+
+    /// Read the current sensor values into `out`.
+    ///
+    /// Unlike [`Sensor::get_data`] this doesn't interpret the values, so it
+    /// also works for sensor types reporting more than 3 values.
+    #[doc(alias = "SDL_GetSensorData")]
+    pub fn get_data_into(&self, out: &mut [f32]) -> Result<(), Error> {
+        let len = c_int::try_from(out.len()).unwrap_or(c_int::MAX);
+        let result = unsafe { SDL_GetSensorData(self.raw, out.as_mut_ptr(), len) };
+
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -0,0 +1,227 @@
+//! Portable storage for game assets and user data
+//!
+//! On desktop platforms title storage maps onto the base path and user
+//! storage onto the pref path, but consoles and sandboxed platforms may need
+//! to mount them asynchronously first. Always check [`Storage::ready`]
+//! before accessing a freshly opened storage.
+//!
+//! Paths inside a storage are always `/` separated, relative to its root.
+//!
+//! ```no_run
+//! use sdl3::storage::Storage;
+//!
+//! let storage = Storage::open_user("My Company", "My Game", None).unwrap();
+//! while !storage.ready() {
+//!     std::thread::sleep(std::time::Duration::from_millis(1));
+//! }
+//! storage.write_file("save.dat", b"level 3").unwrap();
+//! ```
+
+use libc::c_void;
+use std::ffi::CString;
+use std::path::Path;
+use std::ptr;
+
+use crate::get_error;
+use crate::properties::Properties;
+use crate::sys;
+use crate::Error;
+
+fn to_c_string(s: &str) -> Result<CString, Error> {
+    CString::new(s).map_err(|_| Error(format!("{s:?} must not contain NUL bytes")))
+}
+
+fn props_id(props: Option<&Properties>) -> sys::properties::SDL_PropertiesID {
+    props.map_or(Default::default(), |props| props.raw())
+}
+
+/// Wrapper around the `SDL_Storage` object
+pub struct Storage {
+    raw: *mut sys::storage::SDL_Storage,
+}
+
+impl Storage {
+    fn from_ptr(raw: *mut sys::storage::SDL_Storage) -> Result<Storage, Error> {
+        if raw.is_null() {
+            Err(get_error())
+        } else {
+            Ok(Storage { raw })
+        }
+    }
+
+    /// Open the read-only storage holding the application's assets.
+    ///
+    /// `override_path` replaces the default location, which is useful
+    /// during development.
+    #[doc(alias = "SDL_OpenTitleStorage")]
+    pub fn open_title(
+        override_path: Option<&str>,
+        props: Option<&Properties>,
+    ) -> Result<Storage, Error> {
+        let override_path = override_path.map(to_c_string).transpose()?;
+        let raw = unsafe {
+            sys::storage::SDL_OpenTitleStorage(
+                override_path.as_ref().map_or(ptr::null(), |p| p.as_ptr()),
+                props_id(props),
+            )
+        };
+        Storage::from_ptr(raw)
+    }
+
+    /// Open the writable storage for the current user's data, such as save
+    /// games and settings.
+    #[doc(alias = "SDL_OpenUserStorage")]
+    pub fn open_user(org: &str, app: &str, props: Option<&Properties>) -> Result<Storage, Error> {
+        let org = to_c_string(org)?;
+        let app = to_c_string(app)?;
+        let raw = unsafe {
+            sys::storage::SDL_OpenUserStorage(org.as_ptr(), app.as_ptr(), props_id(props))
+        };
+        Storage::from_ptr(raw)
+    }
+
+    /// Open a storage rooted at a directory of the local file system.
+    #[doc(alias = "SDL_OpenFileStorage")]
+    pub fn open_file(path: impl AsRef<Path>) -> Result<Storage, Error> {
+        let path = path
+            .as_ref()
+            .to_str()
+            .ok_or_else(|| Error("storage path must be valid UTF-8".to_owned()))?;
+        let path = to_c_string(path)?;
+        let raw = unsafe { sys::storage::SDL_OpenFileStorage(path.as_ptr()) };
+        Storage::from_ptr(raw)
+    }
+
+    /// Return whether the storage is mounted and can be accessed.
+    #[doc(alias = "SDL_StorageReady")]
+    pub fn ready(&self) -> bool {
+        unsafe { sys::storage::SDL_StorageReady(self.raw) }
+    }
+
+    /// Get the size of the file at `path`, in bytes.
+    #[doc(alias = "SDL_GetStorageFileSize")]
+    pub fn file_size(&self, path: &str) -> Result<u64, Error> {
+        let path = to_c_string(path)?;
+        let mut length = 0;
+        let result =
+            unsafe { sys::storage::SDL_GetStorageFileSize(self.raw, path.as_ptr(), &mut length) };
+        if result {
+            Ok(length)
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Read the whole file at `path`.
+    #[doc(alias = "SDL_ReadStorageFile")]
+    pub fn read_file(&self, path: &str) -> Result<Vec<u8>, Error> {
+        let length = self.file_size(path)?;
+        let length_usize = usize::try_from(length)
+            .map_err(|_| Error(format!("{path:?} is too large to read into memory")))?;
+        let path = to_c_string(path)?;
+        let mut buf = vec![0u8; length_usize];
+        let result = unsafe {
+            sys::storage::SDL_ReadStorageFile(
+                self.raw,
+                path.as_ptr(),
+                buf.as_mut_ptr() as *mut c_void,
+                length,
+            )
+        };
+        if result {
+            Ok(buf)
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Write `data` to the file at `path`, replacing its contents.
+    #[doc(alias = "SDL_WriteStorageFile")]
+    pub fn write_file(&self, path: &str, data: &[u8]) -> Result<(), Error> {
+        let path = to_c_string(path)?;
+        let result = unsafe {
+            sys::storage::SDL_WriteStorageFile(
+                self.raw,
+                path.as_ptr(),
+                data.as_ptr() as *const c_void,
+                data.len() as u64,
+            )
+        };
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Create a directory, along with any missing parents.
+    #[doc(alias = "SDL_CreateStorageDirectory")]
+    pub fn create_directory(&self, path: &str) -> Result<(), Error> {
+        let path = to_c_string(path)?;
+        let result = unsafe { sys::storage::SDL_CreateStorageDirectory(self.raw, path.as_ptr()) };
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Remove a file or an empty directory.
+    #[doc(alias = "SDL_RemoveStoragePath")]
+    pub fn remove_path(&self, path: &str) -> Result<(), Error> {
+        let path = to_c_string(path)?;
+        let result = unsafe { sys::storage::SDL_RemoveStoragePath(self.raw, path.as_ptr()) };
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Move a file or directory within the storage.
+    #[doc(alias = "SDL_RenameStoragePath")]
+    pub fn rename_path(&self, old_path: &str, new_path: &str) -> Result<(), Error> {
+        let old_path = to_c_string(old_path)?;
+        let new_path = to_c_string(new_path)?;
+        let result = unsafe {
+            sys::storage::SDL_RenameStoragePath(self.raw, old_path.as_ptr(), new_path.as_ptr())
+        };
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Get how many bytes can still be written to the storage.
+    #[doc(alias = "SDL_GetStorageSpaceRemaining")]
+    pub fn space_remaining(&self) -> u64 {
+        unsafe { sys::storage::SDL_GetStorageSpaceRemaining(self.raw) }
+    }
+
+    /// Close the storage, reporting whether pending writes were committed.
+    ///
+    /// Dropping the storage closes it too, but ignores any error.
+    #[doc(alias = "SDL_CloseStorage")]
+    pub fn close(self) -> Result<(), Error> {
+        let raw = self.raw;
+        std::mem::forget(self);
+        if unsafe { sys::storage::SDL_CloseStorage(raw) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut sys::storage::SDL_Storage {
+        self.raw
+    }
+}
+
+impl Drop for Storage {
+    #[doc(alias = "SDL_CloseStorage")]
+    fn drop(&mut self) {
+        unsafe { sys::storage::SDL_CloseStorage(self.raw) };
+    }
+}
@@ -18,6 +18,17 @@ use std::ptr;
 use sys::blendmode::SDL_BLENDMODE_NONE;
 use sys::surface::{SDL_ScaleMode, SDL_MUSTLOCK, SDL_SCALEMODE_LINEAR};
 
+/// The direction in which [`SurfaceRef::flip`] mirrors a surface.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum FlipMode {
+    /// Mirror left to right.
+    Horizontal,
+    /// Mirror top to bottom.
+    Vertical,
+    /// Mirror both ways, which is the same as rotating by 180 degrees.
+    Both,
+}
+
 /// Holds a `SDL_Surface`
 ///
 /// When the `SurfaceContext` is dropped, it frees the `SDL_Surface`
@@ -58,6 +69,52 @@ pub struct SurfaceRef {
     _raw: (),
 }
 
+/// Keeps a surface locked for as long as it is alive, unlocking it on drop so the
+/// surface is released even if the code accessing the pixels panics.
+struct SurfaceLock {
+    raw: *mut sys::surface::SDL_Surface,
+    pixels: *mut u8,
+    len: usize,
+}
+
+impl SurfaceLock {
+    fn new(surface: &SurfaceRef) -> Result<SurfaceLock, Error> {
+        unsafe {
+            if !sys::surface::SDL_LockSurface(surface.raw()) {
+                return Err(get_error());
+            }
+            let raw = surface.raw_ref();
+            Ok(SurfaceLock {
+                raw: surface.raw(),
+                pixels: raw.pixels as *mut u8,
+                len: raw.pitch as usize * raw.h as usize,
+            })
+        }
+    }
+}
+
+impl Drop for SurfaceLock {
+    #[doc(alias = "SDL_UnlockSurface")]
+    fn drop(&mut self) {
+        unsafe { sys::surface::SDL_UnlockSurface(self.raw) };
+    }
+}
+
+/// Checks that a caller-provided pixel buffer is large enough for a surface.
+fn validate_data_size(data: &[u8], width: u32, height: u32, pitch: u32) -> Result<(), Error> {
+    if width >= (1 << 31) || height >= (1 << 31) {
+        Err(Error("Image is too large.".to_owned()))
+    } else if pitch >= (1 << 31) {
+        Err(Error("Pitch is too large.".to_owned()))
+    } else if (pitch as usize) * (height as usize) > data.len() {
+        Err(Error(
+            "Pixel buffer is too small for the surface.".to_owned(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 impl AsRef<SurfaceRef> for SurfaceRef {
     fn as_ref(&self) -> &SurfaceRef {
         self
@@ -170,7 +227,27 @@ impl<'a> Surface<'a> {
         }
     }
 
-    /// Creates a new surface from an existing buffer, using a pixel format.
+    /// Creates a new surface that uses an existing buffer for its pixels,
+    /// without copying it.
+    ///
+    /// `pitch` is the length of a row of pixels in bytes, including any padding.
+    /// The buffer must hold at least `pitch * height` bytes, otherwise an error
+    /// is returned.
+    ///
+    /// The surface mutably borrows `data` for its whole lifetime `'a`: SDL reads
+    /// and writes the buffer directly, so it can't be accessed (or moved or
+    /// freed) through any other path until the surface is dropped. This makes it
+    /// possible to wrap pixel data decoded by other crates as an SDL surface.
+    ///
+    /// ```
+    /// use sdl3::pixels::PixelFormat;
+    /// use sdl3::surface::Surface;
+    ///
+    /// let mut pixels = vec![0u8; 4 * 4 * 4];
+    /// let surface = Surface::from_data(&mut pixels, 4, 4, 4 * 4, PixelFormat::RGBA32).unwrap();
+    /// assert_eq!(surface.size(), (4, 4));
+    /// ```
+    #[doc(alias = "SDL_CreateSurfaceFrom")]
     pub fn from_data(
         data: &'a mut [u8],
         width: u32,
@@ -178,8 +255,22 @@ impl<'a> Surface<'a> {
         pitch: u32,
         format: pixels::PixelFormat,
     ) -> Result<Surface<'a>, Error> {
-        let masks = format.into_masks()?;
-        Surface::from_data_pixelmasks(data, width, height, pitch, &masks)
+        validate_data_size(data, width, height, pitch)?;
+        unsafe {
+            let raw = sys::surface::SDL_CreateSurfaceFrom(
+                width as c_int,
+                height as c_int,
+                format.into(),
+                data.as_mut_ptr() as *mut _,
+                pitch as c_int,
+            );
+
+            if raw.is_null() {
+                Err(get_error())
+            } else {
+                Ok(Surface::from_ll(raw))
+            }
+        }
     }
 
     /// Creates a new surface from an existing buffer, using pixel masks.
@@ -191,31 +282,26 @@ impl<'a> Surface<'a> {
         pitch: u32,
         masks: &pixels::PixelMasks,
     ) -> Result<Surface<'a>, Error> {
+        validate_data_size(data, width, height, pitch)?;
         unsafe {
-            if width >= (1 << 31) || height >= (1 << 31) {
-                Err(Error("Image is too large.".to_owned()))
-            } else if pitch >= (1 << 31) {
-                Err(Error("Pitch is too large.".to_owned()))
+            let raw = sys::surface::SDL_CreateSurfaceFrom(
+                width as c_int,
+                height as c_int,
+                sys::pixels::SDL_GetPixelFormatForMasks(
+                    masks.bpp as c_int,
+                    masks.rmask,
+                    masks.gmask,
+                    masks.bmask,
+                    masks.amask,
+                ),
+                data.as_mut_ptr() as *mut _,
+                pitch as c_int,
+            );
+
+            if raw.is_null() {
+                Err(get_error())
             } else {
-                let raw = sys::surface::SDL_CreateSurfaceFrom(
-                    width as c_int,
-                    height as c_int,
-                    sys::pixels::SDL_GetPixelFormatForMasks(
-                        masks.bpp as c_int,
-                        masks.rmask,
-                        masks.gmask,
-                        masks.bmask,
-                        masks.amask,
-                    ),
-                    data.as_mut_ptr() as *mut _,
-                    pitch as c_int,
-                );
-
-                if raw.is_null() {
-                    Err(get_error())
-                } else {
-                    Ok(Surface::from_ll(raw))
-                }
+                Ok(Surface::from_ll(raw))
             }
         }
     }
@@ -343,6 +429,23 @@ impl<'a> Surface<'a> {
     /// The only change is this case is that `Canvas` has a
     /// better API to draw stuff in the `Surface` in that case, but don't expect any performance
     /// changes, there will be none.
+    ///
+    /// No window or video subsystem is needed, which makes this useful for
+    /// offline rendering and tests. Call [`Canvas::present`] to make sure all
+    /// drawing commands have reached the surface before reading its pixels.
+    ///
+    /// ```
+    /// use sdl3::pixels::{Color, PixelFormat};
+    /// use sdl3::render::FRect;
+    /// use sdl3::surface::Surface;
+    ///
+    /// let surface = Surface::new(32, 32, PixelFormat::RGBA32).unwrap();
+    /// let mut canvas = surface.into_canvas().unwrap();
+    /// canvas.set_draw_color(Color::RGB(0, 128, 255));
+    /// canvas.fill_rect(FRect::new(8.0, 8.0, 16.0, 16.0)).unwrap();
+    /// canvas.present();
+    /// let surface = canvas.into_surface();
+    /// ```
     pub fn into_canvas(self) -> Result<Canvas<Surface<'a>>, Error> {
         Canvas::from_surface(self)
     }
@@ -409,35 +512,34 @@ impl SurfaceRef {
     /// Locks a surface so that the pixels can be directly accessed safely.
     #[doc(alias = "SDL_LockSurface")]
     pub fn with_lock<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
-        unsafe {
-            if !sys::surface::SDL_LockSurface(self.raw()) {
-                panic!("could not lock surface");
-            }
-
-            let raw_pixels = self.raw_ref().pixels as *const _;
-            let len = self.raw_ref().pitch as usize * (self.raw_ref().h as usize);
-            let pixels = ::std::slice::from_raw_parts(raw_pixels, len);
-            let rv = f(pixels);
-            sys::surface::SDL_UnlockSurface(self.raw());
-            rv
-        }
+        let lock = SurfaceLock::new(self).expect("could not lock surface");
+        let pixels = unsafe { ::std::slice::from_raw_parts(lock.pixels, lock.len) };
+        f(pixels)
     }
 
     /// Locks a surface so that the pixels can be directly accessed safely.
     #[doc(alias = "SDL_LockSurface")]
     pub fn with_lock_mut<R, F: FnOnce(&mut [u8]) -> R>(&mut self, f: F) -> R {
-        unsafe {
-            if !sys::surface::SDL_LockSurface(self.raw()) {
-                panic!("could not lock surface");
-            }
+        let lock = SurfaceLock::new(self).expect("could not lock surface");
+        let pixels = unsafe { ::std::slice::from_raw_parts_mut(lock.pixels, lock.len) };
+        f(pixels)
+    }
 
-            let raw_pixels = self.raw_ref().pixels as *mut _;
-            let len = self.raw_ref().pitch as usize * (self.raw_ref().h as usize);
-            let pixels = ::std::slice::from_raw_parts_mut(raw_pixels, len);
-            let rv = f(pixels);
-            sys::surface::SDL_UnlockSurface(self.raw());
-            rv
-        }
+    /// Locks a surface and passes its pixels and pitch (the length of a row in
+    /// bytes, including padding) to `f`.
+    ///
+    /// Unlike [`SurfaceRef::with_lock_mut`], a failure to lock the surface is
+    /// returned as an error. The surface is unlocked when `f` returns, even if
+    /// it panics.
+    #[doc(alias = "SDL_LockSurface")]
+    pub fn try_with_lock_mut<R, F: FnOnce(&mut [u8], usize) -> R>(
+        &mut self,
+        f: F,
+    ) -> Result<R, Error> {
+        let lock = SurfaceLock::new(self)?;
+        let pitch = self.pitch() as usize;
+        let pixels = unsafe { ::std::slice::from_raw_parts_mut(lock.pixels, lock.len) };
+        Ok(f(pixels, pitch))
     }
 
     /// Returns the Surface's pixel buffer if the Surface doesn't require locking
@@ -533,9 +635,16 @@ impl SurfaceRef {
         }
     }
 
+    /// Sets the color that is treated as transparent when blitting this
+    /// surface, or disables the color key if `enable` is `false`.
+    ///
+    /// The color is mapped to the surface's pixel format (and palette, if it
+    /// has one) before being set.
     #[doc(alias = "SDL_SetSurfaceColorKey")]
     pub fn set_color_key(&mut self, enable: bool, color: pixels::Color) -> Result<(), Error> {
-        let key = color.to_u32(&self.pixel_format());
+        let key = unsafe {
+            sys::surface::SDL_MapSurfaceRGBA(self.raw(), color.r, color.g, color.b, color.a)
+        };
         let result = unsafe { sys::surface::SDL_SetSurfaceColorKey(self.raw(), enable, key) };
         if result {
             Ok(())
@@ -544,17 +653,35 @@ impl SurfaceRef {
         }
     }
 
-    /// The function will fail if the surface doesn't have color key enabled.
+    /// Returns whether the surface has a color key set.
+    #[doc(alias = "SDL_SurfaceHasColorKey")]
+    pub fn has_color_key(&self) -> bool {
+        unsafe { sys::surface::SDL_SurfaceHasColorKey(self.raw()) }
+    }
+
+    /// Gets the color key of the surface.
+    ///
+    /// Returns an error if the surface has no color key.
     #[doc(alias = "SDL_GetSurfaceColorKey")]
     pub fn color_key(&self) -> Result<pixels::Color, Error> {
         let mut key = 0;
 
-        // SDL_GetSurfaceColorKey does not mutate, but requires a non-const pointer anyway.
-
         let result = unsafe { sys::surface::SDL_GetSurfaceColorKey(self.raw(), &mut key) };
 
         if result {
-            Ok(pixels::Color::from_u32(&self.pixel_format(), key))
+            let (mut r, mut g, mut b, mut a) = (0, 0, 0, 0);
+            unsafe {
+                sys::pixels::SDL_GetRGBA(
+                    key,
+                    self.pixel_format().pixel_format_details(),
+                    sys::surface::SDL_GetSurfacePalette(self.raw()),
+                    &mut r,
+                    &mut g,
+                    &mut b,
+                    &mut a,
+                )
+            };
+            Ok(pixels::Color::RGBA(r, g, b, a))
         } else {
             Err(get_error())
         }
@@ -609,6 +736,27 @@ impl SurfaceRef {
         }
     }
 
+    /// Mirrors the surface's pixels in place.
+    #[doc(alias = "SDL_FlipSurface")]
+    pub fn flip(&mut self, mode: FlipMode) -> Result<(), Error> {
+        match mode {
+            FlipMode::Horizontal => self.flip_raw(sys::surface::SDL_FLIP_HORIZONTAL),
+            FlipMode::Vertical => self.flip_raw(sys::surface::SDL_FLIP_VERTICAL),
+            FlipMode::Both => {
+                self.flip_raw(sys::surface::SDL_FLIP_HORIZONTAL)?;
+                self.flip_raw(sys::surface::SDL_FLIP_VERTICAL)
+            }
+        }
+    }
+
+    fn flip_raw(&mut self, flip: sys::surface::SDL_FlipMode) -> Result<(), Error> {
+        if unsafe { sys::surface::SDL_FlipSurface(self.raw(), flip) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
     #[allow(clippy::clone_on_copy)]
     pub fn fill_rects(&mut self, rects: &[Rect], color: pixels::Color) -> Result<(), Error> {
         for rect in rects.iter() {
@@ -1,6 +1,7 @@
 use crate::sys;
 use libc::c_void;
 use std::ptr::NonNull;
+use std::time::Duration;
 
 /// Constructs a new timer using the boxed closure `callback`.
 ///
@@ -29,6 +30,43 @@ pub fn add_timer(delay: u32, callback: TimerCallback) -> Timer {
     }
 }
 
+/// Constructs a new timer that first fires after `interval`.
+///
+/// Like [`add_timer`], but with [`Duration`]s: `callback` returns
+/// `Some(interval)` to be called again after `interval`, or `None` to stop.
+/// Intervals are rounded down to whole milliseconds, with a minimum of one.
+///
+/// ```no_run
+/// use sdl3::timer::add_interval_timer;
+/// use std::time::Duration;
+///
+/// let _sdl_context = sdl3::init().unwrap();
+/// let mut ticks = 0;
+/// let _timer = add_interval_timer(Duration::from_millis(100), move || {
+///     ticks += 1;
+///     println!("tick {ticks}");
+///     (ticks < 10).then_some(Duration::from_millis(100))
+/// });
+/// ```
+#[must_use = "if unused the Timer will be dropped immediately"]
+pub fn add_interval_timer<F>(interval: Duration, mut callback: F) -> Timer
+where
+    F: FnMut() -> Option<Duration> + Send + 'static,
+{
+    add_timer(
+        duration_to_interval(interval),
+        Box::new(move || callback().map_or(0, duration_to_interval)),
+    )
+}
+
+/// Convert `duration` to a non-zero millisecond interval, since zero cancels
+/// a timer.
+fn duration_to_interval(duration: Duration) -> u32 {
+    u32::try_from(duration.as_millis())
+        .unwrap_or(u32::MAX)
+        .max(1)
+}
+
 /// Gets the number of milliseconds elapsed since the timer subsystem was initialized.
 ///
 /// It's recommended to use another library for timekeeping, such as `time`.
@@ -45,16 +83,96 @@ pub fn delay(ms: u32) {
     unsafe { sys::timer::SDL_Delay(ms) }
 }
 
+/// Gets the number of nanoseconds elapsed since the timer subsystem was initialized.
+#[doc(alias = "SDL_GetTicksNS")]
+pub fn ticks_ns() -> u64 {
+    unsafe { sys::timer::SDL_GetTicksNS() }
+}
+
+/// Sleeps the current thread for the specified amount of nanoseconds.
+///
+/// This is as precise as the platform's sleep allows, which may still
+/// overshoot by a millisecond or more.
+#[doc(alias = "SDL_DelayNS")]
+pub fn delay_ns(ns: u64) {
+    unsafe { sys::timer::SDL_DelayNS(ns) }
+}
+
+/// Gets the current value of the high resolution counter.
+///
+/// Only differences between two values are meaningful; divide them by
+/// [`performance_frequency`] to get seconds.
 #[doc(alias = "SDL_GetPerformanceCounter")]
 pub fn performance_counter() -> u64 {
     unsafe { sys::timer::SDL_GetPerformanceCounter() }
 }
 
+/// Gets the number of [`performance_counter`] increments per second.
 #[doc(alias = "SDL_GetPerformanceFrequency")]
 pub fn performance_frequency() -> u64 {
     unsafe { sys::timer::SDL_GetPerformanceFrequency() }
 }
 
+/// Sleeps away the rest of each frame to hold a target frame rate.
+///
+/// Frame deadlines are scheduled from the previous deadline rather than from
+/// when [`FrameLimiter::wait`] returned, so rounding errors in the sleeps
+/// don't accumulate. If a frame runs so late that a whole frame was missed,
+/// the schedule restarts from now instead of rushing to catch up.
+///
+/// ```no_run
+/// use sdl3::timer::FrameLimiter;
+///
+/// let mut limiter = FrameLimiter::new(60.0);
+/// loop {
+///     // handle events, update and render...
+///     limiter.wait();
+/// }
+/// ```
+pub struct FrameLimiter {
+    frame_length: u64,
+    last_frame: u64,
+}
+
+impl FrameLimiter {
+    /// # Panics
+    ///
+    /// Panics if `target_fps` isn't a positive, finite number.
+    pub fn new(target_fps: f32) -> FrameLimiter {
+        assert!(
+            target_fps.is_finite() && target_fps > 0.0,
+            "target_fps must be positive and finite, got {target_fps}"
+        );
+        FrameLimiter {
+            frame_length: (performance_frequency() as f64 / target_fps as f64) as u64,
+            last_frame: performance_counter(),
+        }
+    }
+
+    /// Sleeps until the current frame has taken its share of time.
+    ///
+    /// Frame lengths too long to schedule, from a vanishingly small target
+    /// frame rate, don't wait at all.
+    pub fn wait(&mut self) {
+        let now = performance_counter();
+        let Some(deadline) = self.last_frame.checked_add(self.frame_length) else {
+            self.last_frame = now;
+            return;
+        };
+
+        if now < deadline {
+            let remaining =
+                (deadline - now) as u128 * 1_000_000_000 / performance_frequency() as u128;
+            delay_ns(remaining as u64);
+            self.last_frame = deadline;
+        } else if now - deadline > self.frame_length {
+            self.last_frame = now;
+        } else {
+            self.last_frame = deadline;
+        }
+    }
+}
+
 /// Type alias for the timer callback function.
 pub type TimerCallback = Box<dyn FnMut() -> u32 + Send + 'static>;
 
@@ -110,6 +228,48 @@ mod test {
 
     use crate::timer::add_timer;
 
+    #[test]
+    fn test_performance_counter_is_monotonic() {
+        assert!(super::performance_frequency() > 0);
+
+        let first = super::performance_counter();
+        let second = super::performance_counter();
+        assert!(second >= first);
+
+        let before = super::ticks_ns();
+        super::delay_ns(1_000_000);
+        assert!(super::ticks_ns() >= before + 1_000_000);
+    }
+
+    #[test]
+    fn test_frame_limiter_paces_frames() {
+        let mut limiter = super::FrameLimiter::new(100.0);
+        let start = std::time::Instant::now();
+        for _ in 0..10 {
+            limiter.wait();
+        }
+        let elapsed = start.elapsed();
+        assert!(
+            elapsed >= Duration::from_millis(80) && elapsed <= Duration::from_millis(300),
+            "10 frames at 100fps took {elapsed:?}"
+        );
+    }
+
+    #[test]
+    fn test_frame_limiter_tiny_fps_does_not_overflow() {
+        let mut limiter = super::FrameLimiter::new(f32::MIN_POSITIVE);
+        let start = std::time::Instant::now();
+        limiter.wait();
+        limiter.wait();
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_frame_limiter_rejects_nan_fps() {
+        super::FrameLimiter::new(f32::NAN);
+    }
+
     #[test]
     fn test_timer_runs_multiple_times() {
         let _sdl_context = crate::sdl::init().unwrap();
@@ -136,6 +296,24 @@ mod test {
         assert_eq!(*num, 9);
     }
 
+    #[test]
+    fn test_interval_timer_stops_on_none() {
+        let _sdl_context = crate::sdl::init().unwrap();
+
+        let local_num = Arc::new(Mutex::new(0));
+        let timer_num = local_num.clone();
+
+        let _timer = super::add_interval_timer(Duration::from_millis(10), move || {
+            let mut num = timer_num.lock().unwrap();
+            *num += 1;
+            (*num < 3).then_some(Duration::from_millis(10))
+        });
+
+        std::thread::sleep(Duration::from_millis(150));
+        let num = local_num.lock().unwrap();
+        assert_eq!(*num, 3);
+    }
+
     #[test]
     fn test_timer_runs_at_least_once() {
         let _sdl_context = crate::sdl::init().unwrap();
@@ -1,7 +1,7 @@
 use crate::sys;
-use std::ffi::c_void;
+use std::ffi::{c_void, CStr};
 use sys::stdinc::SDL_free;
-use sys::touch::SDL_TouchID;
+use sys::touch::{SDL_TouchDeviceType, SDL_TouchID};
 
 pub type Finger = sys::touch::SDL_Finger;
 pub struct TouchId(SDL_TouchID);
@@ -54,7 +54,97 @@ pub fn num_touch_devices() -> Vec<TouchId> {
 pub fn num_touch_fingers(touch: TouchId) -> i32 {
     let mut count = 0;
     unsafe {
-        sys::touch::SDL_GetTouchFingers(touch.into(), &mut count);
+        let fingers = sys::touch::SDL_GetTouchFingers(touch.into(), &mut count);
+        SDL_free(fingers as *mut c_void);
     }
     count
 }
+
+/// The kind of surface a touch device reports from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TouchDeviceType {
+    /// A touch screen with window-relative coordinates.
+    Direct,
+    /// A trackpad with absolute device coordinates.
+    IndirectAbsolute,
+    /// A trackpad with screen cursor-relative coordinates.
+    IndirectRelative,
+    Invalid,
+}
+
+impl TouchDeviceType {
+    pub fn from_ll(raw: SDL_TouchDeviceType) -> TouchDeviceType {
+        match raw {
+            SDL_TouchDeviceType::DIRECT => TouchDeviceType::Direct,
+            SDL_TouchDeviceType::INDIRECT_ABSOLUTE => TouchDeviceType::IndirectAbsolute,
+            SDL_TouchDeviceType::INDIRECT_RELATIVE => TouchDeviceType::IndirectRelative,
+            _ => TouchDeviceType::Invalid,
+        }
+    }
+}
+
+/// A registered touch device, such as a touch screen or trackpad.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TouchDevice {
+    id: SDL_TouchID,
+}
+
+impl TouchDevice {
+    /// Get every registered touch device.
+    ///
+    /// Devices only show up once SDL has seen them, which on some platforms
+    /// means after the first touch event.
+    #[doc(alias = "SDL_GetTouchDevices")]
+    pub fn all() -> Vec<TouchDevice> {
+        num_touch_devices()
+            .into_iter()
+            .map(|id| TouchDevice { id: id.0 })
+            .collect()
+    }
+
+    /// The id of the device, as found in `Event::FingerDown` and friends.
+    pub fn id(&self) -> u64 {
+        self.id.0
+    }
+
+    /// Get the name of the device, if it has one.
+    #[doc(alias = "SDL_GetTouchDeviceName")]
+    pub fn name(&self) -> Option<String> {
+        let name = unsafe { sys::touch::SDL_GetTouchDeviceName(self.id) };
+        if name.is_null() {
+            None
+        } else {
+            Some(
+                unsafe { CStr::from_ptr(name) }
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+        }
+    }
+
+    /// Get the type of the device.
+    #[doc(alias = "SDL_GetTouchDeviceType")]
+    pub fn device_type(&self) -> TouchDeviceType {
+        TouchDeviceType::from_ll(unsafe { sys::touch::SDL_GetTouchDeviceType(self.id) })
+    }
+
+    /// Get the fingers currently touching the device.
+    #[doc(alias = "SDL_GetTouchFingers")]
+    pub fn fingers(&self) -> Vec<Finger> {
+        let mut count = 0;
+        let fingers = unsafe { sys::touch::SDL_GetTouchFingers(self.id, &mut count) };
+
+        if fingers.is_null() {
+            return Vec::new();
+        }
+
+        let result = unsafe { std::slice::from_raw_parts(fingers, count as usize) }
+            .iter()
+            .map(|&finger| unsafe { *finger })
+            .collect();
+
+        unsafe { SDL_free(fingers as *mut c_void) };
+
+        result
+    }
+}
@@ -1,14 +1,16 @@
 use crate::{
     get_error,
+    gpu::Device,
     libc::c_int,
     pixels::Color,
     render::TextureCreator,
     ttf::{
         sys::{
-            TTF_CreateRendererTextEngine, TTF_CreateText, TTF_DestroyRendererTextEngine,
-            TTF_DestroyText, TTF_DrawRendererText, TTF_GetTextSize, TTF_SetTextColor,
-            TTF_SetTextFont, TTF_SetTextString, TTF_SetTextWrapWidth, TTF_Text, TTF_TextEngine,
-            TTF_UpdateText,
+            TTF_CreateGPUTextEngine, TTF_CreateRendererTextEngine, TTF_CreateText,
+            TTF_DestroyGPUTextEngine, TTF_DestroyRendererTextEngine, TTF_DestroyText,
+            TTF_DrawRendererText, TTF_GetTextPosition, TTF_GetTextSize, TTF_SetTextColor,
+            TTF_SetTextFont, TTF_SetTextPosition, TTF_SetTextString, TTF_SetTextWrapWidth,
+            TTF_Text, TTF_TextEngine, TTF_UpdateText,
         },
         Font,
     },
@@ -16,17 +18,42 @@ use crate::{
 };
 use std::ffi::CString;
 
+/// Creates [`Text`] objects and caches the glyphs they are drawn with.
+///
+/// Unlike rendering a [`Font`] to a surface, a `Text` can change its string
+/// every frame without rasterizing the whole string again.
 pub struct TextEngine {
     raw: *mut TTF_TextEngine,
+    // Keeps the device of a GPU text engine alive; `None` for a renderer
+    // text engine.
+    device: Option<Device>,
 }
 impl TextEngine {
+    /// Creates a text engine drawing with the renderer of `creator`, see
+    /// [`Text::draw`].
     #[doc(alias = "TTF_CreateRendererTextEngine")]
     pub fn new<T>(creator: &TextureCreator<T>) -> Result<Self, Error> {
         let raw = unsafe { TTF_CreateRendererTextEngine(creator.raw()) };
         if raw.is_null() {
             Err(get_error())
         } else {
-            Ok(Self { raw })
+            Ok(Self { raw, device: None })
+        }
+    }
+
+    /// Creates a text engine for drawing with the GPU API. The glyph atlas
+    /// and geometry of its texts are available through the `sdl3-ttf-sys`
+    /// `TTF_GetGPUTextDrawData` function on [`Text::raw`].
+    #[doc(alias = "TTF_CreateGPUTextEngine")]
+    pub fn new_gpu(device: &Device) -> Result<Self, Error> {
+        let raw = unsafe { TTF_CreateGPUTextEngine(device.raw()) };
+        if raw.is_null() {
+            Err(get_error())
+        } else {
+            Ok(Self {
+                raw,
+                device: Some(device.clone()),
+            })
         }
     }
 
@@ -48,10 +75,15 @@ impl TextEngine {
 }
 impl Drop for TextEngine {
     fn drop(&mut self) {
-        unsafe { TTF_DestroyRendererTextEngine(self.raw) };
+        if self.device.is_some() {
+            unsafe { TTF_DestroyGPUTextEngine(self.raw) };
+        } else {
+            unsafe { TTF_DestroyRendererTextEngine(self.raw) };
+        }
     }
 }
 
+/// A string laid out with a font, created by [`TextEngine::create_text`].
 pub struct Text {
     raw: *mut TTF_Text,
 }
@@ -70,6 +102,8 @@ impl Text {
         }
     }
 
+    /// Draws the text at `(x, y)` with a renderer text engine. Fails for
+    /// texts of a GPU text engine.
     #[doc(alias = "TTF_DrawRendererText")]
     pub fn draw(&self, x: f32, y: f32) -> Result<(), Error> {
         let ok = unsafe { TTF_DrawRendererText(self.raw, x, y) };
@@ -88,6 +122,26 @@ impl Text {
         (w as u32, h as u32)
     }
 
+    /// Sets the offset the text is drawn at, relative to the position
+    /// passed to [`Text::draw`].
+    #[doc(alias = "TTF_SetTextPosition")]
+    pub fn set_position(&mut self, x: i32, y: i32) -> Result<(), Error> {
+        let ok = unsafe { TTF_SetTextPosition(self.raw, x, y) };
+        if ok {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    #[doc(alias = "TTF_GetTextPosition")]
+    pub fn position(&self) -> (i32, i32) {
+        let mut x: c_int = 0;
+        let mut y: c_int = 0;
+        unsafe { TTF_GetTextPosition(self.raw, &mut x, &mut y) };
+        (x, y)
+    }
+
     #[doc(alias = "TTF_SetTextFont")]
     pub fn set_font(&mut self, font: &Font) -> Result<(), Error> {
         let ok = unsafe { TTF_SetTextFont(self.raw, font.raw()) };
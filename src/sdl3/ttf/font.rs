@@ -19,6 +19,7 @@ pub type Hinting = ttf::TTF_HintingFlags;
 
 bitflags! {
     /// The styling of a font.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
     pub struct FontStyle: u32 {
         const NORMAL        = ttf::TTF_STYLE_NORMAL.0 as u32;
         const BOLD          = ttf::TTF_STYLE_BOLD.0 as u32;
@@ -149,6 +150,59 @@ impl<'f, 'text> PartialRendering<'f, 'text> {
         convert_to_surface(raw)
     }
 
+    /// Renders the text in *solid* mode but wrapping the words if the width
+    /// exceeds the given maximum width. A width of 0 only wraps on newlines.
+    /// See [the SDL3_TTF docs](https://wiki.libsdl.org/SDL3_ttf/TTF_RenderText_Solid_Wrapped)
+    /// for an explanation of the mode.
+    #[doc(alias = "TTF_RenderText_Solid_Wrapped")]
+    pub fn solid_wrapped<'b, T>(self, color: T, wrap_max_width: i32) -> FontResult<Surface<'b>>
+    where
+        T: Into<Color>,
+    {
+        let source = self.text.convert()?;
+        let color = color.into().into();
+        let raw = unsafe {
+            ttf::TTF_RenderText_Solid_Wrapped(
+                self.font.raw(),
+                source.as_ptr(),
+                0,
+                color,
+                wrap_max_width,
+            )
+        };
+        convert_to_surface(raw)
+    }
+
+    /// Renders the text in *shaded* mode but wrapping the words if the width
+    /// exceeds the given maximum width. A width of 0 only wraps on newlines.
+    /// See [the SDL3_TTF docs](https://wiki.libsdl.org/SDL3_ttf/TTF_RenderText_Shaded_Wrapped)
+    /// for an explanation of the mode.
+    #[doc(alias = "TTF_RenderText_Shaded_Wrapped")]
+    pub fn shaded_wrapped<'b, T>(
+        self,
+        color: T,
+        background: T,
+        wrap_max_width: i32,
+    ) -> FontResult<Surface<'b>>
+    where
+        T: Into<Color>,
+    {
+        let source = self.text.convert()?;
+        let foreground = color.into().into();
+        let background = background.into().into();
+        let raw = unsafe {
+            ttf::TTF_RenderText_Shaded_Wrapped(
+                self.font.raw(),
+                source.as_ptr(),
+                0,
+                foreground,
+                background,
+                wrap_max_width,
+            )
+        };
+        convert_to_surface(raw)
+    }
+
     /// Renders the text in *blended* mode.
     /// See [the SDL3_TTF docs](https://wiki.libsdl.org/SDL3_ttf/TTF_RenderText_Blended)
     /// for an explanation.
@@ -170,7 +224,7 @@ impl<'f, 'text> PartialRendering<'f, 'text> {
     }
 
     /// Renders the text in *blended* mode but wrapping the words if the width
-    /// exceeds the given maximum width.
+    /// exceeds the given maximum width. A width of 0 only wraps on newlines.
     /// See [the SDL3_TTF docs](https://wiki.libsdl.org/SDL3_ttf/TTF_RenderText_Blended_Wrapped)
     /// for an explanation of the mode.
     #[doc(alias = "TTF_RenderText_Blended_Wrapped")]
@@ -341,6 +395,25 @@ impl<'r> Font<'r> {
         }
     }
 
+    /// Renders `text` in *blended* mode, wrapping words that would go past
+    /// `wrap_width` pixels onto the next line. A width of 0 only wraps on
+    /// newlines.
+    ///
+    /// This is a shortcut for `font.render(text).blended_wrapped(color, wrap_width)`.
+    #[doc(alias = "TTF_RenderText_Blended_Wrapped")]
+    pub fn render_wrapped<T>(
+        &self,
+        text: &str,
+        color: T,
+        wrap_width: u32,
+    ) -> FontResult<Surface<'static>>
+    where
+        T: Into<Color>,
+    {
+        let wrap_width = i32::try_from(wrap_width).unwrap_or(i32::MAX);
+        self.render(text).blended_wrapped(color, wrap_width)
+    }
+
     /// Starts specifying a rendering of the given UTF-8-encoded character.
     pub fn render_char<'a>(&'a self, ch: char) -> PartialRendering<'a, 'static> {
         let mut s = String::new();
@@ -369,6 +442,33 @@ impl<'r> Font<'r> {
         }
     }
 
+    /// Measures how much of `text` fits in `max_width` pixels.
+    ///
+    /// Returns the width of the part that fits and its length in bytes, so
+    /// `&text[..length]` is the text that would be drawn.
+    #[doc(alias = "TTF_MeasureString")]
+    pub fn measure(&self, text: &str, max_width: u32) -> FontResult<(u32, usize)> {
+        let c_string = RenderableText::Utf8(text).convert()?;
+        let max_width = c_int::try_from(max_width).unwrap_or(c_int::MAX);
+        let mut width = 0;
+        let mut length = 0;
+        let res = unsafe {
+            ttf::TTF_MeasureString(
+                self.raw,
+                c_string.as_ptr(),
+                0,
+                max_width,
+                &mut width,
+                &mut length,
+            )
+        };
+        if res {
+            Ok((width as u32, length))
+        } else {
+            Err(FontError::SdlError(get_error()))
+        }
+    }
+
     /// Returns the width and height of the given text when rendered using this
     /// font.
     pub fn size_of_char(&self, ch: char) -> FontResult<(u32, u32)> {
@@ -382,11 +482,13 @@ impl<'r> Font<'r> {
     pub fn get_style(&self) -> FontStyle {
         unsafe {
             let raw = ttf::TTF_GetFontStyle(self.raw);
-            FontStyle::from_bits_truncate(u32::from(raw))
+            FontStyle::from_bits_truncate(raw.0)
         }
     }
 
     /// Sets the font's style flags.
+    ///
+    /// Bold and italic are synthesized if the font face doesn't provide them.
     #[doc(alias = "TTF_SetFontStyle")]
     pub fn set_style(&mut self, styles: FontStyle) {
         unsafe { ttf::TTF_SetFontStyle(self.raw, ttf::TTF_FontStyleFlags(styles.bits())) }
@@ -398,10 +500,14 @@ impl<'r> Font<'r> {
         unsafe { ttf::TTF_GetFontOutline(self.raw) as u16 }
     }
 
-    /// Sets the width of the font's outline.
+    /// Sets the width of the font's outline in pixels, 0 to disable it.
     #[doc(alias = "TTF_SetFontOutline")]
-    pub fn set_outline_width(&mut self, width: u16) -> bool {
-        unsafe { ttf::TTF_SetFontOutline(self.raw, width as c_int) }
+    pub fn set_outline_width(&mut self, width: u16) -> Result<(), Error> {
+        if unsafe { ttf::TTF_SetFontOutline(self.raw, width as c_int) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
     /// Returns the font's freetype hints.
@@ -448,10 +554,18 @@ impl<'r> Font<'r> {
         unsafe { ttf::TTF_GetFontDescent(self.raw) as i32 }
     }
 
+    /// Returns the recommended distance between the baselines of two lines
+    /// of text rendered with this font.
+    #[doc(alias = "TTF_GetFontLineSkip")]
+    pub fn line_skip(&self) -> i32 {
+        unsafe { ttf::TTF_GetFontLineSkip(self.raw) as i32 }
+    }
+
     /// Returns the recommended line spacing for text rendered with this font.
+    /// Same as [`Font::line_skip`].
     #[doc(alias = "TTF_GetFontLineSkip")]
     pub fn recommended_line_spacing(&self) -> i32 {
-        unsafe { ttf::TTF_GetFontLineSkip(self.raw) as i32 }
+        self.line_skip()
     }
 
     /// Returns the number of faces in this font.
@@ -508,7 +622,7 @@ impl<'r> Font<'r> {
 
     /// Returns the glyph metrics of the given character in this font face.
     #[doc(alias = "TTF_GetGlyphMetrics")]
-    pub fn find_glyph_metrics(&self, ch: char) -> Option<GlyphMetrics> {
+    pub fn glyph_metrics(&self, ch: char) -> Result<GlyphMetrics, Error> {
         let mut minx = 0;
         let mut maxx = 0;
         let mut miny = 0;
@@ -526,7 +640,7 @@ impl<'r> Font<'r> {
             )
         };
         if ret {
-            Some(GlyphMetrics {
+            Ok(GlyphMetrics {
                 minx,
                 maxx,
                 miny,
@@ -534,10 +648,17 @@ impl<'r> Font<'r> {
                 advance,
             })
         } else {
-            None
+            Err(get_error())
         }
     }
 
+    /// Returns the glyph metrics of the given character in this font face,
+    /// or `None` if they are unavailable. See [`Font::glyph_metrics`].
+    #[doc(alias = "TTF_GetGlyphMetrics")]
+    pub fn find_glyph_metrics(&self, ch: char) -> Option<GlyphMetrics> {
+        self.glyph_metrics(ch).ok()
+    }
+
     /// Returns the kerning size between the glyphs of two UNICODE codepoints.
     #[doc(alias = "TTF_GetGlyphKerning")]
     pub fn get_glyph_kerning(&self, previous_ch: char, ch: char) -> Result<i32, Error> {
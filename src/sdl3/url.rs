@@ -45,6 +45,10 @@ impl error::Error for OpenUrlError {
 /// There is no way to tell if the system successfully opened the provided URL,
 /// an `Ok` result only means that something was launched to try to handle it.
 ///
+/// The URL is passed to the system as is, so any scheme the system handles
+/// works, like `mailto:` or `file:`. Only URLs containing a NUL byte are
+/// rejected, with [`OpenUrlError::InvalidUrl`].
+///
 /// # Examples
 ///
 /// ```no_run
@@ -52,6 +56,7 @@ impl error::Error for OpenUrlError {
 ///
 /// open_url("https://github.com/revmischa/sdl3-rs")
 ///   .expect("Opening URLs not supported on this platform");
+/// open_url("mailto:bugs@example.com?subject=Crash%20report").ok();
 /// ```
 #[doc(alias = "SDL_OpenURL")]
 pub fn open_url(url: &str) -> Result<(), OpenUrlError> {
@@ -2,12 +2,15 @@
 Querying SDL Version
  */
 
+use std::ffi::CStr;
 use std::fmt;
 
 use crate::sys;
 
 /// A structure that contains information about the version of SDL in use.
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+///
+/// Versions are ordered by major, then minor, then patch version.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct Version {
     /// major version
     pub major: u8,
@@ -18,6 +21,27 @@ pub struct Version {
 }
 
 impl Version {
+    pub const fn new(major: u8, minor: u8, patch: u8) -> Version {
+        Version {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// The version of SDL these bindings were compiled against.
+    #[doc(alias = "SDL_VERSION")]
+    pub fn current() -> Version {
+        Version::from_ll(sys::version::SDL_VERSION)
+    }
+
+    /// The version of SDL linked at runtime, which can be newer than
+    /// [`Version::current`].
+    #[doc(alias = "SDL_GetVersion")]
+    pub fn linked() -> Version {
+        version()
+    }
+
     /// Convert a raw sdl version number to Version.
     pub fn from_ll(v: i32) -> Version {
         // pub const SDL_VERSION: i32 = _; // 3_001_003i32
@@ -41,3 +65,17 @@ pub fn version() -> Version {
     let version = sys::version::SDL_GetVersion();
     Version::from_ll(version)
 }
+
+/// Get the code revision of the linked SDL, like
+/// `"SDL-3.2.0-gabcdef123"`. It may be an empty string for custom builds.
+#[doc(alias = "SDL_GetRevision")]
+pub fn revision() -> String {
+    unsafe {
+        let revision = sys::version::SDL_GetRevision();
+        if revision.is_null() {
+            String::new()
+        } else {
+            CStr::from_ptr(revision).to_string_lossy().into_owned()
+        }
+    }
+}
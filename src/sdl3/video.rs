@@ -2,7 +2,7 @@ use crate::common::{validate_int, IntegerOrSdlError};
 use crate::get_error;
 use crate::pixels::PixelFormat;
 use crate::properties::{Properties, PropertiesError};
-use crate::rect::Rect;
+use crate::rect::{Point, Rect};
 use crate::render::{create_renderer, WindowCanvas};
 use crate::surface::SurfaceRef;
 use crate::Error;
@@ -947,6 +947,10 @@ impl Display {
         }
     }
 
+    /// Get the desktop display mode, i.e. the mode the display uses when no
+    /// fullscreen window has changed it.
+    ///
+    /// See [`Display::get_current_mode`] for the mode the display is using right now.
     #[doc(alias = "SDL_GetDesktopDisplayMode")]
     pub fn get_mode(&self) -> Result<DisplayMode, Error> {
         unsafe {
@@ -958,6 +962,21 @@ impl Display {
         }
     }
 
+    /// Get the display mode the display is currently using.
+    ///
+    /// This differs from the desktop mode returned by [`Display::get_mode`] while
+    /// an exclusive fullscreen window has switched the display to another mode.
+    #[doc(alias = "SDL_GetCurrentDisplayMode")]
+    pub fn get_current_mode(&self) -> Result<DisplayMode, Error> {
+        unsafe {
+            let raw_mode = sys::video::SDL_GetCurrentDisplayMode(self.id);
+            if raw_mode.is_null() {
+                return Err(get_error());
+            }
+            Ok(DisplayMode::from_ll(&*raw_mode))
+        }
+    }
+
     #[doc(alias = "SDL_GetClosestFullscreenDisplayMode")]
     pub fn get_closest_display_mode(
         &self,
@@ -1123,6 +1142,46 @@ impl VideoSubsystem {
         }
     }
 
+    /// Get every connected display together with its desktop bounds.
+    ///
+    /// Displays whose bounds cannot be queried (e.g. because they were disconnected in the
+    /// meantime) are skipped.
+    #[doc(alias = "SDL_GetDisplays")]
+    #[doc(alias = "SDL_GetDisplayBounds")]
+    pub fn get_display_bounds_all(&self) -> Result<Vec<(Display, Rect)>, Error> {
+        Ok(self
+            .displays()?
+            .into_iter()
+            .filter_map(|display| display.get_bounds().ok().map(|bounds| (display, bounds)))
+            .collect())
+    }
+
+    /// Get the display containing `point`, or `None` if it lies outside every display.
+    #[doc(alias = "SDL_GetDisplayForPoint")]
+    pub fn get_display_at_point(&self, point: Point) -> Option<Display> {
+        let id = unsafe { sys::video::SDL_GetDisplayForPoint(point.raw()) };
+        if id == 0 {
+            None
+        } else {
+            Some(Display::from_ll(id))
+        }
+    }
+
+    /// Get the desktop display mode of `display`, which is the mode it returns
+    /// to when no fullscreen window is using it. Same as [`Display::get_mode`].
+    #[doc(alias = "SDL_GetDesktopDisplayMode")]
+    pub fn desktop_display_mode(&self, display: &Display) -> Result<DisplayMode, Error> {
+        display.get_mode()
+    }
+
+    /// Get the mode `display` is currently using, which differs from the
+    /// desktop mode while an exclusive fullscreen window has changed it. Same as
+    /// [`Display::get_current_mode`].
+    #[doc(alias = "SDL_GetCurrentDisplayMode")]
+    pub fn current_display_mode(&self, display: &Display) -> Result<DisplayMode, Error> {
+        display.get_current_mode()
+    }
+
     /// Get primary display ID.
     #[doc(alias = "SDL_GetPrimaryDisplay")]
     pub fn get_primary_display(&self) -> Result<Display, Error> {
@@ -1134,6 +1193,16 @@ impl VideoSubsystem {
         }
     }
 
+    /// Get the display that contains the center of `window`.
+    ///
+    /// Useful for HiDPI applications that need to react to a window moving between monitors:
+    /// re-query this on `WindowEvent::Moved` or `WindowEvent::DisplayChanged` and read the new
+    /// display's content scale.
+    #[doc(alias = "SDL_GetDisplayForWindow")]
+    pub fn get_display_for_window(&self, window: &Window) -> Result<Display, Error> {
+        window.get_display()
+    }
+
     #[doc(alias = "SDL_ScreenSaverEnabled")]
     pub fn is_screen_saver_enabled(&self) -> bool {
         unsafe { sys::video::SDL_ScreenSaverEnabled() }
@@ -1867,6 +1936,62 @@ impl Window {
         }
     }
 
+    /// Make `context` the current OpenGL context of the calling thread and bind it to this
+    /// window.
+    ///
+    /// # Example
+    ///
+    /// A minimal immediate-mode triangle, loading the few GL entry points it needs through
+    /// `gl_get_proc_address` instead of a GL loader crate:
+    ///
+    /// ```no_run
+    /// use sdl3::video::GLProfile;
+    /// use std::mem::transmute;
+    ///
+    /// let sdl_context = sdl3::init().unwrap();
+    /// let video_subsystem = sdl_context.video().unwrap();
+    /// video_subsystem.gl_attr().set_context_profile(GLProfile::Compatibility);
+    ///
+    /// let window = video_subsystem
+    ///     .window("GL triangle", 800, 600)
+    ///     .opengl()
+    ///     .build()
+    ///     .unwrap();
+    /// let context = window.gl_create_context().unwrap();
+    /// window.gl_make_current(&context).unwrap();
+    ///
+    /// type Color4f = unsafe extern "C" fn(f32, f32, f32, f32);
+    /// type Bitfield = unsafe extern "C" fn(u32);
+    /// type Enum = unsafe extern "C" fn(u32);
+    /// type Vertex2f = unsafe extern "C" fn(f32, f32);
+    /// type Color3f = unsafe extern "C" fn(f32, f32, f32);
+    /// type End = unsafe extern "C" fn();
+    /// const GL_COLOR_BUFFER_BIT: u32 = 0x4000;
+    /// const GL_TRIANGLES: u32 = 0x0004;
+    ///
+    /// let proc = |name| video_subsystem.gl_get_proc_address(name).unwrap();
+    /// unsafe {
+    ///     let clear_color: Color4f = transmute(proc("glClearColor"));
+    ///     let clear: Bitfield = transmute(proc("glClear"));
+    ///     let begin: Enum = transmute(proc("glBegin"));
+    ///     let color: Color3f = transmute(proc("glColor3f"));
+    ///     let vertex: Vertex2f = transmute(proc("glVertex2f"));
+    ///     let end: End = transmute(proc("glEnd"));
+    ///
+    ///     clear_color(0.1, 0.1, 0.1, 1.0);
+    ///     clear(GL_COLOR_BUFFER_BIT);
+    ///     begin(GL_TRIANGLES);
+    ///     color(1.0, 0.0, 0.0);
+    ///     vertex(-0.5, -0.5);
+    ///     color(0.0, 1.0, 0.0);
+    ///     vertex(0.5, -0.5);
+    ///     color(0.0, 0.0, 1.0);
+    ///     vertex(0.0, 0.5);
+    ///     end();
+    /// }
+    ///
+    /// window.gl_swap_window().unwrap();
+    /// ```
     #[doc(alias = "SDL_GL_MakeCurrent")]
     pub fn gl_make_current(&self, context: &GLContext) -> Result<(), Error> {
         unsafe {
@@ -1878,9 +2003,18 @@ impl Window {
         }
     }
 
+    /// Update the window with OpenGL rendering by presenting the back buffer.
+    ///
+    /// With double-buffering, everything drawn since the last swap becomes visible and the
+    /// contents of the new back buffer are undefined. If a swap interval is set via
+    /// `VideoSubsystem::gl_set_swap_interval`, this may block until the next vertical refresh.
     #[doc(alias = "SDL_GL_SwapWindow")]
-    pub fn gl_swap_window(&self) {
-        unsafe { sys::video::SDL_GL_SwapWindow(self.context.raw) };
+    pub fn gl_swap_window(&self) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_GL_SwapWindow(self.context.raw) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
     /// Get the names of the Vulkan instance extensions needed to create a surface with `vulkan_create_surface`.
@@ -1915,6 +2049,11 @@ impl Window {
 
     /// Create a Vulkan rendering surface for a window.
     ///
+    /// With the `ash` feature enabled, `VkInstance` and `VkSurfaceKHR` are
+    /// `ash::vk::Instance` and `ash::vk::SurfaceKHR`. The instance must have been
+    /// created with the extensions returned by [`Window::vulkan_instance_extensions`].
+    /// See `examples/vulkan-ash.rs`.
+    ///
     /// # Safety
     /// The `VkInstance` must be a valid Vulkan instance created using a prior call to the
     /// [`vkCreateInstance`](https://www.khronos.org/registry/vulkan/specs/1.1-extensions/man/html/vkCreateInstance.html)
@@ -2162,20 +2301,32 @@ impl Window {
     ///
     /// On windowing systems where changes are immediate, this does nothing.
     ///
-    /// Returns `true` on success or `false` if the operation timed out before the
-    /// window was in the requested state.
+    /// Returns an error if the operation timed out before the window was in the
+    /// requested state.
     ///
     /// This function should only be called on the main thread.
     #[doc(alias = "SDL_SyncWindow")]
-    pub fn sync(&self) -> bool {
-        unsafe { sys::video::SDL_SyncWindow(self.context.raw) }
+    pub fn sync(&self) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_SyncWindow(self.context.raw) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
+    /// Get the number of pixels per window coordinate unit (point).
+    ///
+    /// This is the ratio between [`Window::size_in_pixels`] and [`Window::size`],
+    /// e.g. 2.0 for a window created with `high_pixel_density` on a Retina
+    /// display. Use it to size render targets, and [`Window::display_scale`] to
+    /// size content.
     #[doc(alias = "SDL_GetWindowPixelDensity")]
     pub fn pixel_density(&self) -> f32 {
         unsafe { sys::video::SDL_GetWindowPixelDensity(self.context.raw) }
     }
 
+    /// Get the size of the window's client area in pixels, which can be larger
+    /// than [`Window::size`] (measured in points) on high density displays.
     #[doc(alias = "SDL_GetWindowSizeInPixels")]
     pub fn size_in_pixels(&self) -> (u32, u32) {
         let mut w: c_int = 0;
@@ -2184,14 +2335,18 @@ impl Window {
         (w as u32, h as u32)
     }
 
+    /// Set the smallest size the user can resize the window to.
+    ///
+    /// Both `width` and `height` must be nonzero.
     #[doc(alias = "SDL_SetWindowMinimumSize")]
     pub fn set_minimum_size(&mut self, width: u32, height: u32) -> Result<(), IntegerOrSdlError> {
-        let w = validate_int(width, "width")?;
-        let h = validate_int(height, "height")?;
-        unsafe {
-            sys::video::SDL_SetWindowMinimumSize(self.context.raw, w, h);
+        let w = validate_size_limit(width, "width")?;
+        let h = validate_size_limit(height, "height")?;
+        if unsafe { sys::video::SDL_SetWindowMinimumSize(self.context.raw, w, h) } {
+            Ok(())
+        } else {
+            Err(IntegerOrSdlError::SdlError(get_error()))
         }
-        Ok(())
     }
 
     #[doc(alias = "SDL_GetWindowMinimumSize")]
@@ -2202,14 +2357,18 @@ impl Window {
         (w as u32, h as u32)
     }
 
+    /// Set the largest size the user can resize the window to.
+    ///
+    /// Both `width` and `height` must be nonzero.
     #[doc(alias = "SDL_SetWindowMaximumSize")]
     pub fn set_maximum_size(&mut self, width: u32, height: u32) -> Result<(), IntegerOrSdlError> {
-        let w = validate_int(width, "width")?;
-        let h = validate_int(height, "height")?;
-        unsafe {
-            sys::video::SDL_SetWindowMaximumSize(self.context.raw, w, h);
+        let w = validate_size_limit(width, "width")?;
+        let h = validate_size_limit(height, "height")?;
+        if unsafe { sys::video::SDL_SetWindowMaximumSize(self.context.raw, w, h) } {
+            Ok(())
+        } else {
+            Err(IntegerOrSdlError::SdlError(get_error()))
         }
-        Ok(())
     }
 
     #[doc(alias = "SDL_GetWindowMaximumSize")]
@@ -2220,39 +2379,103 @@ impl Window {
         (w as u32, h as u32)
     }
 
+    /// Add or remove the window's border and title bar.
     #[doc(alias = "SDL_SetWindowBordered")]
-    pub fn set_bordered(&mut self, bordered: bool) -> bool {
-        unsafe { sys::video::SDL_SetWindowBordered(self.context.raw, bordered) }
+    pub fn set_bordered(&mut self, bordered: bool) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_SetWindowBordered(self.context.raw, bordered) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Allow or prevent the user from resizing the window.
+    #[doc(alias = "SDL_SetWindowResizable")]
+    pub fn set_resizable(&mut self, resizable: bool) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_SetWindowResizable(self.context.raw, resizable) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
+    /// Keep the window above all other windows, e.g. to pin an overlay.
+    #[doc(alias = "SDL_SetWindowAlwaysOnTop")]
+    pub fn set_always_on_top(&mut self, on_top: bool) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_SetWindowAlwaysOnTop(self.context.raw, on_top) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Show the window.
     #[doc(alias = "SDL_ShowWindow")]
-    pub fn show(&mut self) -> bool {
-        unsafe { sys::video::SDL_ShowWindow(self.context.raw) }
+    pub fn show(&mut self) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_ShowWindow(self.context.raw) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
+    /// Hide the window.
     #[doc(alias = "SDL_HideWindow")]
-    pub fn hide(&mut self) -> bool {
-        unsafe { sys::video::SDL_HideWindow(self.context.raw) }
+    pub fn hide(&mut self) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_HideWindow(self.context.raw) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
+    /// Raise the window above other windows and request input focus.
     #[doc(alias = "SDL_RaiseWindow")]
-    pub fn raise(&mut self) -> bool {
-        unsafe { sys::video::SDL_RaiseWindow(self.context.raw) }
+    pub fn raise(&mut self) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_RaiseWindow(self.context.raw) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
+    /// Make the window as large as possible.
+    ///
+    /// On asynchronous windowing systems the change may not have taken effect
+    /// when this returns; call [`Window::sync`] to wait for it.
     #[doc(alias = "SDL_MaximizeWindow")]
-    pub fn maximize(&mut self) -> bool {
-        unsafe { sys::video::SDL_MaximizeWindow(self.context.raw) }
+    pub fn maximize(&mut self) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_MaximizeWindow(self.context.raw) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
+    /// Minimize the window to an iconic representation.
+    ///
+    /// On asynchronous windowing systems the change may not have taken effect
+    /// when this returns; call [`Window::sync`] to wait for it.
     #[doc(alias = "SDL_MinimizeWindow")]
-    pub fn minimize(&mut self) -> bool {
-        unsafe { sys::video::SDL_MinimizeWindow(self.context.raw) }
+    pub fn minimize(&mut self) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_MinimizeWindow(self.context.raw) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
+    /// Restore the size and position of a minimized or maximized window.
+    ///
+    /// On asynchronous windowing systems the change may not have taken effect
+    /// when this returns; call [`Window::sync`] to wait for it.
     #[doc(alias = "SDL_RestoreWindow")]
-    pub fn restore(&mut self) -> bool {
-        unsafe { sys::video::SDL_RestoreWindow(self.context.raw) }
+    pub fn restore(&mut self) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_RestoreWindow(self.context.raw) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
     pub fn fullscreen_state(&self) -> FullscreenType {
@@ -2295,26 +2518,76 @@ impl Window {
         }
     }
 
+    /// Set whether the window grabs the keyboard.
+    ///
+    /// While grabbed, system shortcuts such as Alt+Tab or the Meta/Super key are
+    /// delivered to the window instead of the system, where the platform allows
+    /// it. The grab is only active while the window has keyboard focus.
     #[doc(alias = "SDL_SetWindowKeyboardGrab")]
-    pub fn set_keyboard_grab(&mut self, grabbed: bool) -> bool {
-        unsafe { sys::video::SDL_SetWindowKeyboardGrab(self.context.raw, grabbed) }
+    pub fn set_keyboard_grab(&mut self, grabbed: bool) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_SetWindowKeyboardGrab(self.context.raw, grabbed) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
+    /// Set whether the window grabs the mouse, confining the cursor to the
+    /// window while it has input focus.
+    ///
+    /// Use [`Window::set_mouse_rect`] to confine the cursor to a smaller area.
     #[doc(alias = "SDL_SetWindowMouseGrab")]
-    pub fn set_mouse_grab(&mut self, grabbed: bool) -> bool {
-        unsafe { sys::video::SDL_SetWindowMouseGrab(self.context.raw, grabbed) }
+    pub fn set_mouse_grab(&mut self, grabbed: bool) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_SetWindowMouseGrab(self.context.raw, grabbed) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
+    /// Returns whether SDL is currently grabbing the keyboard for this window.
+    ///
+    /// This is only true while the grab is active, which requires input focus.
+    /// A grab requested on an unfocused window shows up as
+    /// [`WindowFlags::KEYBOARD_GRABBED`] instead.
     #[doc(alias = "SDL_GetWindowKeyboardGrab")]
     pub fn keyboard_grab(&self) -> bool {
         unsafe { sys::video::SDL_GetWindowKeyboardGrab(self.context.raw) }
     }
 
+    /// Returns whether SDL is currently grabbing the mouse for this window.
+    ///
+    /// This is only true while the grab is active, which requires input focus.
+    /// A grab requested with [`Window::set_mouse_grab`] on an unfocused window
+    /// shows up as [`WindowFlags::MOUSE_GRABBED`] instead.
     #[doc(alias = "SDL_GetWindowMouseGrab")]
     pub fn mouse_grab(&self) -> bool {
         unsafe { sys::video::SDL_GetWindowMouseGrab(self.context.raw) }
     }
 
+    /// Set relative mouse mode for this window.
+    ///
+    /// While enabled, the cursor is hidden, constrained to the window and SDL reports continuous
+    /// relative motion even when the mouse is at the edge of the window.
+    ///
+    /// This is the same as `MouseUtil::set_relative_mouse_mode`, but reports failures.
+    #[doc(alias = "SDL_SetWindowRelativeMouseMode")]
+    pub fn set_relative_mouse_mode(&mut self, on: bool) -> Result<(), Error> {
+        let result = unsafe { sys::mouse::SDL_SetWindowRelativeMouseMode(self.context.raw, on) };
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    #[doc(alias = "SDL_GetWindowRelativeMouseMode")]
+    pub fn relative_mouse_mode(&self) -> bool {
+        unsafe { sys::mouse::SDL_GetWindowRelativeMouseMode(self.context.raw) }
+    }
+
+    /// Confine the cursor to `rect`, relative to the window, while the window
+    /// has input focus. `None` removes the confinement.
     #[doc(alias = "SDL_SetWindowMouseRect")]
     pub fn set_mouse_rect<R>(&self, rect: R) -> Result<(), Error>
     where
@@ -2335,6 +2608,7 @@ impl Window {
         }
     }
 
+    /// Get the area the cursor is confined to, if any.
     #[doc(alias = "SDL_GetWindowMouseRect")]
     pub fn mouse_rect(&self) -> Option<Rect> {
         unsafe {
@@ -2352,6 +2626,24 @@ impl Window {
         }
     }
 
+    /// Get the area of the window that is safe for interactive content.
+    ///
+    /// Some devices have portions of the screen which are partially obscured or not interactive,
+    /// such as the notch or the home indicator on phones. The returned rect is relative to the
+    /// window and covers the whole window on platforms without such areas.
+    #[doc(alias = "SDL_GetWindowSafeArea")]
+    pub fn safe_area(&self) -> Result<Rect, Error> {
+        let mut out = mem::MaybeUninit::uninit();
+        let result =
+            unsafe { sys::video::SDL_GetWindowSafeArea(self.context.raw, out.as_mut_ptr()) };
+        if result {
+            let out = unsafe { out.assume_init() };
+            Ok(Rect::from_ll(out))
+        } else {
+            Err(get_error())
+        }
+    }
+
     /// Set the transparency of the window. The given value will be clamped internally between
     /// `0.0` (fully transparent), and `1.0` (fully opaque).
     ///
@@ -2535,3 +2827,13 @@ pub fn drivers() -> DriverIterator {
         index: 0,
     }
 }
+
+/// Validates a window size limit, which must be a nonzero positive C integer.
+fn validate_size_limit(value: u32, name: &'static str) -> Result<c_int, IntegerOrSdlError> {
+    if value == 0 {
+        return Err(IntegerOrSdlError::SdlError(Error(format!(
+            "window size limit '{name}' must be nonzero"
+        ))));
+    }
+    validate_int(value, name)
+}
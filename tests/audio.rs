@@ -146,3 +146,106 @@ fn audio_stream_put_and_clear() {
     );
     stream.flush().expect("flush failed");
 }
+
+#[test]
+fn audio_stream_converts_mono_f32_to_stereo_i16() {
+    use sdl3::audio::{AudioFormat, AudioSpec, AudioStreamOwner};
+
+    let src = AudioSpec::new(Some(48_000), Some(1), Some(AudioFormat::f32_sys()));
+    let dst = AudioSpec::new(Some(48_000), Some(2), Some(AudioFormat::s16_sys()));
+    let stream = AudioStreamOwner::new(&src, &dst).expect("failed to create audio stream");
+
+    let samples = vec![0.5f32; 256];
+    stream.put_data_f32(&samples).expect("put_data_f32 failed");
+    stream.flush().expect("flush failed");
+
+    // Each 4 byte mono sample becomes two 2 byte stereo samples: same byte count.
+    let expected = samples.len() * 2 * std::mem::size_of::<i16>();
+    let mut out = vec![0u8; expected * 2];
+    let mut read = 0;
+    loop {
+        let n = stream.get_data(&mut out[read..]).expect("get_data failed");
+        if n == 0 {
+            break;
+        }
+        read += n;
+    }
+    assert_eq!(read, expected);
+    assert_eq!(stream.available_bytes().expect("available_bytes failed"), 0);
+}
+
+#[test]
+fn audio_playback_devices_have_names_and_specs() {
+    let Some((_sdl, audio)) = init_audio_subsystem() else {
+        return;
+    };
+
+    // Headless machines may have no device at all, which is fine.
+    let devices = audio.playback_devices().expect("playback_devices failed");
+    for device in &devices {
+        assert!(
+            !device.name.is_empty(),
+            "device {:?} has no name",
+            device.id
+        );
+        assert!(device.spec.channels.unwrap_or(1) > 0);
+    }
+
+    audio.recording_devices().expect("recording_devices failed");
+}
+
+#[test]
+fn load_wav_io_from_embedded_bytes() {
+    // 16-bit stereo PCM at 8kHz, two sample frames.
+    let mut wav = Vec::new();
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&36u32.to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    wav.extend_from_slice(&2u16.to_le_bytes()); // channels
+    wav.extend_from_slice(&8_000u32.to_le_bytes()); // sample rate
+    wav.extend_from_slice(&32_000u32.to_le_bytes()); // byte rate
+    wav.extend_from_slice(&4u16.to_le_bytes()); // block align
+    wav.extend_from_slice(&16u16.to_le_bytes()); // bits per sample
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&8u32.to_le_bytes());
+    wav.extend_from_slice(&[1, 0, 2, 0, 3, 0, 4, 0]);
+
+    let mut stream = sdl3::iostream::IOStream::from_vec(wav).unwrap();
+    let (spec, buffer) = sdl3::audio::load_wav_io(&mut stream).unwrap();
+
+    assert_eq!(spec.channels, Some(2));
+    assert_eq!(spec.freq, Some(8_000));
+    assert_eq!(spec.format, Some(sdl3::audio::AudioFormat::S16LE));
+    assert_eq!(buffer, [1, 0, 2, 0, 3, 0, 4, 0]);
+}
+
+#[test]
+fn audio_device_pause_and_gain() {
+    let Some((_sdl, audio)) = init_audio_subsystem() else {
+        return;
+    };
+
+    let device = match audio.open_playback_device(&sdl3::audio::AudioSpec::default()) {
+        Ok(device) => device,
+        Err(err) => {
+            eprintln!("Skipping device test: failed to open dummy playback device: {err}");
+            return;
+        }
+    };
+
+    assert!(device.pause(), "pause failed");
+    assert!(
+        device.is_paused(),
+        "device should report paused after pausing"
+    );
+    assert!(device.resume(), "resume failed");
+    assert!(
+        !device.is_paused(),
+        "device should report unpaused after resuming"
+    );
+
+    device.set_gain(0.5).expect("set_gain failed");
+    assert_eq!(device.gain().expect("gain failed"), 0.5);
+}
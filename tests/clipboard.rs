@@ -23,3 +23,102 @@ fn test_clipboard() {
     // get it back
     assert_eq!(clipboard.clipboard_text(), Ok(text.to_string()));
 }
+
+#[test]
+fn clipboard_text_round_trip() {
+    let sdl_context = match sdl3::init() {
+        Ok(ctx) => ctx,
+        Err(err) => {
+            eprintln!("Skipping clipboard test: failed to init SDL: {err}");
+            return;
+        }
+    };
+    let video_subsystem = match sdl_context.video() {
+        Ok(video) => video,
+        Err(err) => {
+            eprintln!("Skipping clipboard test: no video device available: {err}");
+            return;
+        }
+    };
+    let clipboard = video_subsystem.clipboard();
+
+    let text = "héllo, clipboard ✓";
+    clipboard
+        .set_clipboard_text(text)
+        .expect("set_clipboard_text failed");
+    assert!(clipboard.has_clipboard_text());
+    assert_eq!(clipboard.clipboard_text().as_deref(), Ok(text));
+
+    // Interior NULs can't be passed to SDL, and must not panic.
+    assert!(clipboard.set_clipboard_text("nul\0byte").is_err());
+    assert_eq!(clipboard.clipboard_text().as_deref(), Ok(text));
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn primary_selection_round_trip() {
+    let sdl_context = match sdl3::init() {
+        Ok(ctx) => ctx,
+        Err(err) => {
+            eprintln!("Skipping primary selection test: failed to init SDL: {err}");
+            return;
+        }
+    };
+    let video_subsystem = match sdl_context.video() {
+        Ok(video) => video,
+        Err(err) => {
+            eprintln!("Skipping primary selection test: no video device available: {err}");
+            return;
+        }
+    };
+    let clipboard = video_subsystem.clipboard();
+
+    clipboard
+        .set_clipboard_text("clipboard")
+        .expect("set_clipboard_text failed");
+    clipboard
+        .set_primary_selection_text("selection")
+        .expect("set_primary_selection_text failed");
+    assert!(clipboard.has_primary_selection_text());
+    assert_eq!(
+        clipboard.primary_selection_text().as_deref(),
+        Ok("selection")
+    );
+    // Setting the selection doesn't touch the clipboard.
+    assert_eq!(clipboard.clipboard_text().as_deref(), Ok("clipboard"));
+}
+
+#[test]
+fn clipboard_data_custom_mime_type() {
+    let sdl_context = match sdl3::init() {
+        Ok(ctx) => ctx,
+        Err(err) => {
+            eprintln!("Skipping clipboard data test: failed to init SDL: {err}");
+            return;
+        }
+    };
+    let video_subsystem = match sdl_context.video() {
+        Ok(video) => video,
+        Err(err) => {
+            eprintln!("Skipping clipboard data test: no video device available: {err}");
+            return;
+        }
+    };
+    let clipboard = video_subsystem.clipboard();
+
+    let mime_type = "application/x-sdl3-rs-test";
+    clipboard
+        .set_clipboard_data(&[mime_type], |requested| {
+            format!("payload for {requested}").into_bytes()
+        })
+        .expect("set_clipboard_data failed");
+
+    assert!(clipboard.has_clipboard_data(mime_type));
+    assert!(!clipboard.has_clipboard_data("application/x-not-offered"));
+    assert_eq!(
+        clipboard
+            .clipboard_data(mime_type)
+            .expect("clipboard_data failed"),
+        format!("payload for {mime_type}").into_bytes()
+    );
+}
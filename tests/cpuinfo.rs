@@ -0,0 +1,30 @@
+use sdl3::cpuinfo::{self, CpuInfo, SimdAlloc};
+
+#[test]
+fn cpu_info_reports_hardware() {
+    let info = CpuInfo::get();
+    assert!(info.logical_cores >= 1);
+    assert!(info.cache_line_size > 0);
+    assert!(info.system_ram > 0);
+    assert_eq!(info.logical_cores, cpuinfo::num_logical_cpu_cores());
+
+    // Every x86_64 CPU has SSE2, and every aarch64 CPU has NEON.
+    #[cfg(target_arch = "x86_64")]
+    assert!(info.sse && info.sse2);
+    #[cfg(target_arch = "aarch64")]
+    assert!(info.neon);
+}
+
+#[test]
+fn simd_alloc_is_aligned() {
+    let alignment = cpuinfo::simd_alignment();
+    assert!(alignment.is_power_of_two());
+
+    for len in [0, 1, 3, 64, 1000] {
+        let mut buffer = SimdAlloc::new(len).unwrap();
+        assert_eq!(buffer.as_ptr() as usize % alignment, 0);
+        assert_eq!(buffer.len(), len);
+        assert!(buffer.as_slice().iter().all(|&b| b == 0));
+        buffer.as_mut_slice().fill(0xab);
+    }
+}
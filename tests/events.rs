@@ -139,3 +139,84 @@ fn test_event_sender_no_subsystem() {
         })
         .is_err());
 }
+
+#[test]
+fn test_poll_in_range() {
+    let _lock = CONTEXT_MUTEX.lock();
+    let sdl = sdl3::init().unwrap();
+    let ev = sdl.event().unwrap();
+    let mut ep = sdl.event_pump().unwrap();
+
+    // start from an empty queue
+    while ep.poll_event().is_some() {}
+
+    ev.push_event(event::Event::Quit { timestamp: 0 }).unwrap();
+    ev.push_event(event::Event::MouseMotion {
+        timestamp: 0,
+        window_id: 0,
+        which: 0,
+        mousestate: sdl3::mouse::MouseState::from_sdl_state(0),
+        x: 12.0,
+        y: 34.0,
+        xrel: 0.0,
+        yrel: 0.0,
+    })
+    .unwrap();
+    ev.push_event(event::Event::Window {
+        timestamp: 0,
+        window_id: 0,
+        win_event: event::WindowEvent::Shown,
+    })
+    .unwrap();
+
+    let mouse = ep.poll_in_range(event::EventType::MouseMotion, event::EventType::MouseWheel);
+    match mouse {
+        Some(event::Event::MouseMotion { x, y, .. }) => assert_eq!((x, y), (12.0, 34.0)),
+        other => panic!("expected mouse motion, got {other:?}"),
+    }
+    assert!(ep
+        .poll_in_range(event::EventType::MouseMotion, event::EventType::MouseWheel)
+        .is_none());
+
+    // the other events were left in the queue
+    let remaining: Vec<_> = ep.poll_iter().collect();
+    assert!(remaining
+        .iter()
+        .any(|e| matches!(e, event::Event::Quit { .. })));
+    assert!(remaining.iter().any(|e| matches!(
+        e,
+        event::Event::Window {
+            win_event: event::WindowEvent::Shown,
+            ..
+        }
+    )));
+}
+
+#[test]
+fn test_push_quit_and_window_event() {
+    let _lock = CONTEXT_MUTEX.lock();
+    let sdl = sdl3::init().unwrap();
+    let ev = sdl.event().unwrap();
+    let mut ep = sdl.event_pump().unwrap();
+
+    while ep.poll_event().is_some() {}
+
+    ev.push_window_event(7, event::WindowEvent::Resized(640, 480))
+        .unwrap();
+    ev.push_quit().unwrap();
+
+    let received: Vec<_> = ep.poll_iter().collect();
+    assert!(received.iter().any(|e| matches!(
+        e,
+        event::Event::Window {
+            window_id: 7,
+            win_event: event::WindowEvent::Resized(640, 480),
+            ..
+        }
+    )));
+    let quit = received
+        .iter()
+        .find(|e| matches!(e, event::Event::Quit { .. }))
+        .expect("the quit event should have been polled");
+    assert!(quit.get_timestamp() > 0);
+}
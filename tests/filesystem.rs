@@ -0,0 +1,95 @@
+use sdl3::filesystem;
+
+#[test]
+fn base_path_is_a_directory() {
+    let base = filesystem::get_base_path().expect("get_base_path failed");
+    assert!(base.is_dir(), "{} is not a directory", base.display());
+}
+
+#[test]
+fn pref_path_creates_directory() {
+    let path =
+        filesystem::get_pref_path("sdl3-rs", "pref-path-test").expect("get_pref_path failed");
+    assert!(path.is_dir(), "{} was not created", path.display());
+    assert!(path.ends_with("pref-path-test"));
+
+    let _ = std::fs::remove_dir(&path);
+}
+
+fn scratch_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("sdl3-rs-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn enumerate_and_glob_directory() {
+    use filesystem::{EnumerationResult, GlobFlags};
+
+    let dir = scratch_dir("enumerate");
+    std::fs::write(dir.join("a.txt"), b"a").unwrap();
+    std::fs::write(dir.join("B.TXT"), b"b").unwrap();
+    std::fs::write(dir.join("c.png"), b"c").unwrap();
+
+    let mut names = Vec::new();
+    filesystem::enumerate_directory(&dir, |_dir, name| {
+        names.push(name.to_str().unwrap().to_owned());
+        EnumerationResult::CONTINUE
+    })
+    .expect("enumerate_directory failed");
+    names.sort();
+    assert_eq!(names, ["B.TXT", "a.txt", "c.png"]);
+
+    // Returning SUCCESS stops after the first entry.
+    let mut seen = 0;
+    filesystem::enumerate_directory(&dir, |_dir, _name| {
+        seen += 1;
+        EnumerationResult::SUCCESS
+    })
+    .expect("enumerate_directory failed");
+    assert_eq!(seen, 1);
+
+    let results = filesystem::glob_directory(&dir, Some("*.txt"), GlobFlags::CASEINSENSITIVE)
+        .expect("glob_directory failed");
+    let mut matches: Vec<_> = results
+        .into_iter()
+        .map(|path| path.to_str().unwrap().to_owned())
+        .collect();
+    matches.sort();
+    assert_eq!(matches, ["B.TXT", "a.txt"]);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn create_copy_rename_and_remove() {
+    use filesystem::PathType;
+
+    let dir = scratch_dir("paths");
+    let nested = dir.join("nested").join("deeper");
+    filesystem::create_directory(&nested).expect("create_directory failed");
+    let info = filesystem::get_path_info(&nested).expect("get_path_info failed");
+    assert_eq!(info.path_type(), PathType::DIRECTORY);
+
+    let original = nested.join("original.bin");
+    std::fs::write(&original, [7u8; 42]).unwrap();
+    let info = filesystem::get_path_info(&original).expect("get_path_info failed");
+    assert_eq!(info.path_type(), PathType::FILE);
+    assert_eq!(info.size(), 42);
+
+    let copy = nested.join("copy.bin");
+    filesystem::copy_file(&original, &copy).expect("copy_file failed");
+    assert_eq!(filesystem::get_path_info(&copy).unwrap().size(), 42);
+
+    let renamed = nested.join("renamed.bin");
+    filesystem::rename_path(&copy, &renamed).expect("rename_path failed");
+    assert!(filesystem::get_path_info(&copy).is_err());
+    assert_eq!(filesystem::get_path_info(&renamed).unwrap().size(), 42);
+
+    filesystem::remove_path(&renamed).expect("remove_path failed");
+    filesystem::remove_path(&original).expect("remove_path failed");
+    assert!(filesystem::get_path_info(&original).is_err());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
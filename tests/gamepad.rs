@@ -0,0 +1,129 @@
+fn init_gamepad_subsystem() -> Option<(sdl3::Sdl, sdl3::GamepadSubsystem)> {
+    let sdl = match sdl3::init() {
+        Ok(sdl) => sdl,
+        Err(err) => {
+            eprintln!("Skipping gamepad test: failed to init SDL: {err}");
+            return None;
+        }
+    };
+    let gamepad = match sdl.gamepad() {
+        Ok(gamepad) => gamepad,
+        Err(err) => {
+            eprintln!("Skipping gamepad test: failed to init gamepad subsystem: {err}");
+            return None;
+        }
+    };
+    Some((sdl, gamepad))
+}
+
+#[test]
+fn add_mapping_increases_mapping_count() {
+    use sdl3::gamepad::MappingStatus;
+
+    let Some((_sdl, gamepad_subsystem)) = init_gamepad_subsystem() else {
+        return;
+    };
+
+    let before = gamepad_subsystem.mappings().expect("mappings failed").len();
+    let mapping = "03000000fe0f0000ad0b000000000000,Synthetic Test Pad,a:b0,b:b1,x:b2,y:b3,leftx:a0,lefty:a1,";
+    let status = gamepad_subsystem
+        .add_mapping(mapping)
+        .expect("add_mapping failed");
+    assert_eq!(status, MappingStatus::Added);
+
+    let mappings = gamepad_subsystem.mappings().expect("mappings failed");
+    assert_eq!(mappings.len(), before + 1);
+    assert!(mappings.iter().any(|m| m.contains("Synthetic Test Pad")));
+
+    // Database files only load the lines whose platform field matches.
+    let platform = sdl3::get_platform();
+    let db = format!(
+        "# synthetic gamecontrollerdb\n\
+         03000000fe0f0000ad0c000000000000,Synthetic Pad Two,a:b0,b:b1,platform:{platform},\n\
+         03000000fe0f0000ad0d000000000000,Synthetic Pad Three,a:b0,b:b1,platform:{platform},\n\
+         03000000fe0f0000ad0e000000000000,Synthetic Pad Other,a:b0,b:b1,platform:Nowhere,\n"
+    );
+    let mut stream = sdl3::iostream::IOStream::from_bytes(db.as_bytes()).unwrap();
+    let added = gamepad_subsystem
+        .add_mappings_from_io(&mut stream)
+        .expect("add_mappings_from_io failed");
+    assert_eq!(added, 2);
+    assert_eq!(
+        gamepad_subsystem.mappings().expect("mappings failed").len(),
+        before + 3
+    );
+}
+
+#[test]
+fn connected_gamepad_type_and_player_index() {
+    use sdl3::gamepad::GamepadType;
+
+    let Some((_sdl, gamepad_subsystem)) = init_gamepad_subsystem() else {
+        return;
+    };
+
+    let ids = gamepad_subsystem.gamepads().expect("gamepads failed");
+    let Some(gamepad) = ids
+        .into_iter()
+        .find_map(|id| gamepad_subsystem.open(id).ok())
+    else {
+        eprintln!("Skipping gamepad test: no gamepad connected");
+        return;
+    };
+
+    assert_ne!(gamepad.r#type(), GamepadType::Unknown);
+
+    let original = gamepad.player_index();
+    gamepad
+        .set_player_index(3)
+        .expect("set_player_index failed");
+    assert_eq!(gamepad.player_index(), Some(3));
+    gamepad
+        .unset_player_index()
+        .expect("unset_player_index failed");
+    assert_eq!(gamepad.player_index(), None);
+    if let Some(index) = original {
+        gamepad.set_player_index(index).unwrap();
+    }
+}
+
+#[cfg(feature = "hidapi")]
+mod gamepad_sensor_test {
+    use sdl3::sensor::SensorType;
+
+    #[test]
+    fn enable_accelerometer_if_present() {
+        let Some((_sdl, gamepad_subsystem)) = super::init_gamepad_subsystem() else {
+            return;
+        };
+
+        let ids = gamepad_subsystem.gamepads().expect("gamepads failed");
+        let Some(gamepad) = ids
+            .into_iter()
+            .find_map(|id| gamepad_subsystem.open(id).ok())
+        else {
+            eprintln!("Skipping gamepad test: no gamepad connected");
+            return;
+        };
+
+        if !unsafe { gamepad.has_sensor(SensorType::Accelerometer) } {
+            eprintln!("Skipping gamepad test: gamepad has no accelerometer");
+            return;
+        }
+
+        gamepad
+            .set_sensor_enabled(SensorType::Accelerometer, true)
+            .expect("set_sensor_enabled failed");
+        assert!(gamepad.sensor_enabled(SensorType::Accelerometer));
+
+        let mut data = [0.0f32; 3];
+        gamepad
+            .get_sensor_data(SensorType::Accelerometer, &mut data)
+            .expect("get_sensor_data failed");
+
+        gamepad
+            .set_sensor_enabled(SensorType::Accelerometer, false)
+            .expect("set_sensor_enabled failed");
+        assert!(!gamepad.sensor_enabled(SensorType::Accelerometer));
+    }
+}
@@ -0,0 +1,239 @@
+use sdl3::gpu::{
+    BufferLocation, BufferRegion, BufferUsageFlags, ColorTargetDescription, Device,
+    GraphicsPipelineTargetInfo, PrimitiveType, Shader, ShaderFormat, ShaderStage, TextureFormat,
+    TransferBufferLocation, TransferBufferUsage,
+};
+
+/// A GPU device along with the SDL state it depends on.
+///
+/// Fields drop in declaration order, so the device goes away before SDL does.
+struct Gpu {
+    device: Device,
+    video: sdl3::VideoSubsystem,
+    _sdl: sdl3::Sdl,
+}
+
+/// Creates a GPU device, or returns `None` (after logging why) if there is no
+/// usable GPU driver, which is common on CI machines.
+fn gpu() -> Option<Gpu> {
+    let sdl = match sdl3::init() {
+        Ok(sdl) => sdl,
+        Err(err) => {
+            eprintln!("Skipping GPU test: failed to init SDL: {err}");
+            return None;
+        }
+    };
+    let video = match sdl.video() {
+        Ok(video) => video,
+        Err(err) => {
+            eprintln!("Skipping GPU test: no video device available: {err}");
+            return None;
+        }
+    };
+    let formats =
+        ShaderFormat::SPIRV | ShaderFormat::DXIL | ShaderFormat::MSL | ShaderFormat::METALLIB;
+    match Device::new(formats, false) {
+        Ok(device) => Some(Gpu {
+            device,
+            video,
+            _sdl: sdl,
+        }),
+        Err(err) => {
+            eprintln!("Skipping GPU test: couldn't create a GPU device: {err}");
+            None
+        }
+    }
+}
+
+#[test]
+fn download_buffer_round_trip() {
+    let Some(gpu) = gpu() else { return };
+    let device = &gpu.device;
+
+    let data: Vec<u8> = (0..=255).collect();
+    let size = data.len() as u32;
+    let buffer = device
+        .create_buffer()
+        .with_size(size)
+        .with_usage(BufferUsageFlags::COMPUTE_STORAGE_READ)
+        .build()
+        .unwrap();
+    let copy = device
+        .create_buffer()
+        .with_size(size)
+        .with_usage(BufferUsageFlags::COMPUTE_STORAGE_READ)
+        .build()
+        .unwrap();
+
+    let upload = device
+        .create_transfer_buffer()
+        .with_size(size)
+        .with_usage(TransferBufferUsage::UPLOAD)
+        .build()
+        .unwrap();
+    let mut map = upload.map::<u8>(device, false);
+    map.mem_mut().copy_from_slice(&data);
+    map.unmap();
+
+    let command_buffer = device.acquire_command_buffer().unwrap();
+    let copy_pass = device.begin_copy_pass(&command_buffer).unwrap();
+    copy_pass.upload_to_gpu_buffer(
+        TransferBufferLocation::new().with_transfer_buffer(&upload),
+        BufferRegion::new().with_buffer(&buffer).with_size(size),
+        false,
+    );
+    copy_pass.copy_buffer_to_buffer(
+        BufferLocation::new().with_buffer(&buffer),
+        BufferLocation::new().with_buffer(&copy),
+        size,
+        false,
+    );
+    device.end_copy_pass(copy_pass);
+    command_buffer.submit().unwrap();
+
+    assert_eq!(device.download_buffer(&buffer, 0..size).unwrap(), data);
+    assert_eq!(device.download_buffer(&copy, 0..size).unwrap(), data);
+    assert_eq!(
+        device.download_buffer(&buffer, 16..32).unwrap(),
+        &data[16..32]
+    );
+    assert!(device.download_buffer(&buffer, 0..0).unwrap().is_empty());
+
+    // Ranges past the end of the buffer are rejected before touching the GPU.
+    assert!(device.download_buffer(&buffer, 0..size + 1).is_err());
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = 32..16;
+    assert!(device.download_buffer(&buffer, reversed).is_err());
+}
+
+#[test]
+fn swapchain_requires_claimed_window() {
+    let Some(gpu) = gpu() else { return };
+    let device = &gpu.device;
+    let window = match gpu.video.window("gpu test", 64, 64).hidden().build() {
+        Ok(window) => window,
+        Err(err) => {
+            eprintln!("Skipping GPU test: couldn't create a window: {err}");
+            return;
+        }
+    };
+
+    let mut command_buffer = device.acquire_command_buffer().unwrap();
+    assert!(command_buffer.acquire_swapchain_texture(&window).is_err());
+    command_buffer.submit().unwrap();
+
+    device.claim_window(&window).unwrap();
+    let mut command_buffer = device.acquire_command_buffer().unwrap();
+    assert!(command_buffer.acquire_swapchain_texture(&window).is_ok());
+    command_buffer.submit().unwrap();
+
+    device.release_window(&window);
+    let mut command_buffer = device.acquire_command_buffer().unwrap();
+    assert!(command_buffer.acquire_swapchain_texture(&window).is_err());
+    command_buffer.submit().unwrap();
+}
+
+#[test]
+fn cached_graphics_pipeline_is_reused() {
+    let Some(gpu) = gpu() else { return };
+    let device = &gpu.device;
+    if device.get_shader_formats() & ShaderFormat::SPIRV != ShaderFormat::SPIRV {
+        eprintln!("Skipping GPU test: the driver doesn't accept SPIR-V shaders");
+        return;
+    }
+
+    let shaders = || {
+        let vertex = device
+            .create_shader()
+            .with_code(
+                ShaderFormat::SPIRV,
+                include_bytes!("../examples/shaders/triangle.vert.spv"),
+                ShaderStage::Vertex,
+            )
+            .with_entrypoint(c"main")
+            .build()
+            .unwrap();
+        let fragment = device
+            .create_shader()
+            .with_code(
+                ShaderFormat::SPIRV,
+                include_bytes!("../examples/shaders/triangle.frag.spv"),
+                ShaderStage::Fragment,
+            )
+            .with_entrypoint(c"main")
+            .build()
+            .unwrap();
+        (vertex, fragment)
+    };
+    let color_targets = [ColorTargetDescription::new().with_format(TextureFormat::R8g8b8a8Unorm)];
+
+    let build = |(vertex, fragment): &(Shader, Shader), primitive_type| {
+        device
+            .create_graphics_pipeline_cached()
+            .with_vertex_shader(vertex)
+            .with_fragment_shader(fragment)
+            .with_primitive_type(primitive_type)
+            .with_target_info(
+                GraphicsPipelineTargetInfo::new().with_color_target_descriptions(&color_targets),
+            )
+            .build()
+            .unwrap()
+    };
+
+    let triangle = shaders();
+    let first = build(&triangle, PrimitiveType::TriangleList);
+    let second = build(&triangle, PrimitiveType::TriangleList);
+    assert_eq!(first.raw(), second.raw());
+
+    let other = build(&triangle, PrimitiveType::TriangleStrip);
+    assert_ne!(first.raw(), other.raw());
+
+    // The cache keeps the dropped shaders alive, so new shaders can't take over
+    // their addresses and pick up the old pipeline.
+    drop(triangle);
+    let fresh = shaders();
+    let third = build(&fresh, PrimitiveType::TriangleList);
+    assert_ne!(first.raw(), third.raw());
+}
+
+#[cfg(feature = "spirv-reflect")]
+mod reflect_test {
+    use sdl3::gpu::ShaderResourceCounts;
+
+    #[test]
+    fn reflect_fragment_sampler() {
+        let counts = ShaderResourceCounts::from_spirv(include_bytes!(
+            "../examples/shaders/cube-texture.frag.spv"
+        ))
+        .unwrap();
+        assert_eq!(
+            counts,
+            ShaderResourceCounts {
+                samplers: 1,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn reflect_buffers() {
+        let vertex = ShaderResourceCounts::from_spirv(include_bytes!(
+            "../examples/shaders/cube-texture.vert.spv"
+        ))
+        .unwrap();
+        assert_eq!(vertex.uniform_buffers, 1);
+        assert_eq!(vertex.samplers, 0);
+
+        let particles = ShaderResourceCounts::from_spirv(include_bytes!(
+            "../examples/shaders/particles.vert.spv"
+        ))
+        .unwrap();
+        assert_eq!(particles.storage_buffers, 1);
+    }
+
+    #[test]
+    fn reflect_rejects_garbage() {
+        assert!(ShaderResourceCounts::from_spirv(&[0; 3]).is_err());
+        assert!(ShaderResourceCounts::from_spirv(&[0; 32]).is_err());
+    }
+}
@@ -0,0 +1,35 @@
+use sdl3::guid::Guid;
+use std::collections::HashSet;
+
+// An Xbox 360 controller on Linux.
+const XBOX_360: &str = "030000005e0400008e02000010010000";
+
+#[test]
+fn guid_round_trip() {
+    let guid: Guid = XBOX_360.parse().unwrap();
+    assert!(!guid.is_zero());
+    assert_eq!(guid.string(), XBOX_360);
+    assert_eq!(guid.to_string(), XBOX_360);
+    assert_eq!(Guid::from_string(XBOX_360).unwrap(), guid);
+
+    // SDL formats in lowercase
+    let upper: Guid = XBOX_360.to_uppercase().parse().unwrap();
+    assert_eq!(upper, guid);
+}
+
+#[test]
+fn guid_rejects_malformed_strings() {
+    assert!("".parse::<Guid>().is_err());
+    assert!("030000005e0400008e0200001001".parse::<Guid>().is_err());
+    assert!("030000005e0400008e02000010010zzz".parse::<Guid>().is_err());
+}
+
+#[test]
+fn guid_hash() {
+    let guid: Guid = XBOX_360.parse().unwrap();
+    let zero: Guid = "00000000000000000000000000000000".parse().unwrap();
+    assert!(zero.is_zero());
+
+    let set: HashSet<Guid> = [guid, zero, guid].into_iter().collect();
+    assert_eq!(set.len(), 2);
+}
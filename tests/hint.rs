@@ -0,0 +1,88 @@
+use sdl3::hint::{self, Hint, HintGuard};
+
+#[test]
+fn set_get_and_reset_hint() {
+    let name = "SDL3_RS_TEST_HINT_SET_GET";
+
+    assert_eq!(hint::get(name), None);
+    assert!(hint::set(name, "first"));
+    assert_eq!(hint::get(name).as_deref(), Some("first"));
+
+    // A lower priority can't replace an override.
+    assert!(hint::set_with_priority(name, "override", &Hint::Override));
+    assert!(!hint::set_with_priority(name, "normal", &Hint::Normal));
+    assert_eq!(hint::get(name).as_deref(), Some("override"));
+
+    assert!(hint::reset(name));
+    assert_eq!(hint::get(name), None);
+}
+
+#[test]
+fn hint_guard_resets_on_drop() {
+    let name = "SDL3_RS_TEST_HINT_GUARD";
+
+    {
+        let _guard = HintGuard::new(name, "guarded");
+        assert_eq!(hint::get(name).as_deref(), Some("guarded"));
+    }
+    assert_eq!(hint::get(name), None);
+}
+
+#[test]
+fn hint_names_match_sdl_constants() {
+    use sdl3::hint::names;
+    use sdl3::sys::hints;
+    use std::ffi::CStr;
+
+    fn sdl_name(raw: *const std::ffi::c_char) -> &'static str {
+        unsafe { CStr::from_ptr(raw) }.to_str().unwrap()
+    }
+
+    let pairs = [
+        (names::RENDER_DRIVER, hints::SDL_HINT_RENDER_DRIVER),
+        (names::RENDER_VSYNC, hints::SDL_HINT_RENDER_VSYNC),
+        (
+            names::MOUSE_RELATIVE_MODE_CENTER,
+            hints::SDL_HINT_MOUSE_RELATIVE_MODE_CENTER,
+        ),
+        (names::VIDEO_DRIVER, hints::SDL_HINT_VIDEO_DRIVER),
+        (names::APP_NAME, hints::SDL_HINT_APP_NAME),
+    ];
+    for (name, raw) in pairs {
+        assert_eq!(name, sdl_name(raw));
+    }
+    assert!(names::ALL.contains(&names::RENDER_DRIVER));
+}
+
+#[test]
+fn hint_callback_sees_changes() {
+    use std::sync::{Arc, Mutex};
+
+    let name = "SDL3_RS_TEST_HINT_CALLBACK";
+    let seen = Arc::new(Mutex::new(Vec::new()));
+
+    let watch = {
+        let seen = seen.clone();
+        hint::add_callback(name, move |hint_name, old, new| {
+            assert_eq!(hint_name, "SDL3_RS_TEST_HINT_CALLBACK");
+            seen.lock()
+                .unwrap()
+                .push((old.map(str::to_owned), new.map(str::to_owned)));
+        })
+        .expect("add_callback failed")
+    };
+
+    hint::set(name, "on");
+    assert_eq!(
+        seen.lock().unwrap().last(),
+        Some(&(None, Some("on".to_owned())))
+    );
+
+    // No more calls once the watch is dropped.
+    drop(watch);
+    let calls = seen.lock().unwrap().len();
+    hint::set(name, "off");
+    assert_eq!(seen.lock().unwrap().len(), calls);
+
+    hint::reset(name);
+}
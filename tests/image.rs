@@ -0,0 +1,37 @@
+#[cfg(feature = "image")]
+mod image_test {
+    use sdl3::image::{LoadSurface, LoadTexture, SaveSurfaceLossy};
+    use sdl3::pixels::{Color, PixelFormat};
+    use sdl3::surface::Surface;
+
+    #[test]
+    fn save_jpg_round_trip() {
+        let mut surface = Surface::new(16, 8, PixelFormat::RGB24).unwrap();
+        surface.fill_rect(None, Color::RGB(200, 40, 40)).unwrap();
+
+        let path = std::env::temp_dir().join("sdl3_save_jpg_round_trip.jpg");
+        surface.save_jpg(&path, 90).unwrap();
+
+        let loaded = Surface::from_file(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(loaded.size(), (16, 8));
+    }
+
+    #[test]
+    fn load_texture_typed_tga() {
+        // 2x2 uncompressed true-color TGA: 18 byte header followed by BGR pixels.
+        #[rustfmt::skip]
+        let tga: [u8; 30] = [
+            0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 24, 0,
+            0, 0, 255, 0, 255, 0,
+            255, 0, 0, 255, 255, 255,
+        ];
+
+        let surface = Surface::new(4, 4, PixelFormat::RGBA8888).unwrap();
+        let canvas = surface.into_canvas().unwrap();
+        let texture_creator = canvas.texture_creator();
+
+        let texture = texture_creator.load_texture_typed(&tga, "TGA").unwrap();
+        assert_eq!((texture.width(), texture.height()), (2, 2));
+    }
+}
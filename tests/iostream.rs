@@ -11,3 +11,48 @@ fn iostream_from_vec() {
 
     assert_eq!(output, logo);
 }
+
+#[test]
+fn iostream_write_then_read_memory() {
+    use std::io::{Seek, SeekFrom, Write};
+
+    let mut buffer = [0u8; 16];
+    {
+        let mut ios = sdl3::iostream::IOStream::from_bytes_mut(&mut buffer).unwrap();
+        ios.write_all(b"hello, sdl").unwrap();
+
+        ios.seek(SeekFrom::Start(7)).unwrap();
+        let mut word = [0u8; 3];
+        ios.read_exact(&mut word).unwrap();
+        assert_eq!(&word, b"sdl");
+
+        assert_eq!(ios.seek(SeekFrom::End(0)).unwrap(), 16);
+    }
+    assert_eq!(&buffer[..10], b"hello, sdl");
+}
+
+#[test]
+fn iostream_dynamic_memory_grows() {
+    use std::io::Write;
+
+    let mut ios = sdl3::iostream::IOStream::from_dynamic_mem().unwrap();
+    assert!(ios.dynamic_memory().is_empty());
+
+    let data: Vec<u8> = (0..=255).cycle().take(10_000).collect();
+    ios.write_all(&data).unwrap();
+    assert_eq!(ios.len(), Some(data.len()));
+    assert_eq!(ios.dynamic_memory(), data);
+}
+
+#[test]
+fn iostream_from_rust_stream() {
+    use std::io::Cursor;
+
+    let logo = std::fs::read("./assets/SDL_logo.bmp").unwrap();
+    let mut ios = sdl3::iostream::IOStream::from_stream(Cursor::new(logo.clone())).unwrap();
+    assert_eq!(ios.len(), Some(logo.len()));
+
+    let surface = sdl3::surface::Surface::load_bmp_rw(&mut ios).unwrap();
+    let reference = sdl3::surface::Surface::load_bmp("./assets/SDL_logo.bmp").unwrap();
+    assert_eq!(surface.size(), reference.size());
+}
@@ -0,0 +1,115 @@
+use sdl3::gamepad::{Axis, Button};
+use sdl3::joystick::{PowerLevel, VirtualJoystickDescription};
+
+fn init_joystick_subsystem() -> Option<(sdl3::Sdl, sdl3::JoystickSubsystem)> {
+    let sdl = match sdl3::init() {
+        Ok(sdl) => sdl,
+        Err(err) => {
+            eprintln!("Skipping joystick test: failed to init SDL: {err}");
+            return None;
+        }
+    };
+    let joystick = match sdl.joystick() {
+        Ok(joystick) => joystick,
+        Err(err) => {
+            eprintln!("Skipping joystick test: failed to init joystick subsystem: {err}");
+            return None;
+        }
+    };
+    Some((sdl, joystick))
+}
+
+#[test]
+fn virtual_joystick_power_info_is_unknown() {
+    let Some((_sdl, joystick_subsystem)) = init_joystick_subsystem() else {
+        return;
+    };
+
+    let desc = VirtualJoystickDescription::new()
+        .with_axes(vec![Axis::LeftX, Axis::LeftY])
+        .with_buttons(vec![Button::South])
+        .name("Power Test Stick");
+    let connection = joystick_subsystem
+        .attach_virtual_joystick(desc)
+        .expect("attach_virtual_joystick failed");
+    let joystick = joystick_subsystem
+        .open(connection.id())
+        .expect("failed to open virtual joystick");
+
+    // Virtual devices have no battery to report on.
+    let info = joystick.power_info().expect("power_info failed");
+    assert_eq!(info.state, PowerLevel::Unknown);
+    assert_eq!(info.battery_percentage(), None);
+}
+
+#[test]
+fn virtual_joystick_reports_axis_value() {
+    let Some((_sdl, joystick_subsystem)) = init_joystick_subsystem() else {
+        return;
+    };
+
+    let before = joystick_subsystem
+        .joysticks()
+        .expect("joysticks failed")
+        .len();
+
+    let desc = VirtualJoystickDescription::new()
+        .with_axes(vec![Axis::LeftX, Axis::LeftY])
+        .name("Axis Test Stick");
+    let connection = joystick_subsystem
+        .attach_virtual_joystick(desc)
+        .expect("attach_virtual_joystick failed");
+    assert!(joystick_subsystem.is_virtual(connection.id()));
+    assert_eq!(
+        joystick_subsystem
+            .joysticks()
+            .expect("joysticks failed")
+            .len(),
+        before + 1
+    );
+
+    let joystick = joystick_subsystem
+        .open(connection.id())
+        .expect("failed to open virtual joystick");
+    assert_eq!(joystick.num_axes(), 2);
+
+    joystick
+        .set_virtual_axis(1, 12_345)
+        .expect("set_virtual_axis failed");
+    // Virtual input is only latched on the next joystick update.
+    joystick_subsystem.update();
+    assert_eq!(joystick.axis(1).expect("axis failed"), 12_345);
+
+    // Dropping the connection detaches the device.
+    drop(joystick);
+    drop(connection);
+    assert_eq!(
+        joystick_subsystem
+            .joysticks()
+            .expect("joysticks failed")
+            .len(),
+        before
+    );
+}
+
+#[test]
+fn virtual_joystick_connected_until_detached() {
+    let Some((_sdl, joystick_subsystem)) = init_joystick_subsystem() else {
+        return;
+    };
+
+    let desc = VirtualJoystickDescription::new()
+        .with_buttons(vec![Button::South])
+        .name("Connection Test Stick");
+    let connection = joystick_subsystem
+        .attach_virtual_joystick(desc)
+        .expect("attach_virtual_joystick failed");
+    let joystick = joystick_subsystem
+        .open(connection.id())
+        .expect("failed to open virtual joystick");
+    assert!(joystick.connected());
+
+    // Dropping the connection detaches the virtual device.
+    drop(connection);
+    assert!(!joystick.connected());
+}
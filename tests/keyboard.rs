@@ -0,0 +1,31 @@
+use sdl3::keyboard::{Keycode, Scancode};
+use std::collections::HashSet;
+
+#[test]
+fn all_scancodes() {
+    let all: Vec<Scancode> = Scancode::all().collect();
+    assert_eq!(all.len(), 246);
+
+    let unique: HashSet<_> = all.iter().copied().collect();
+    assert_eq!(unique.len(), all.len());
+    for scancode in [Scancode::A, Scancode::Z, Scancode::Up, Scancode::Left] {
+        assert!(unique.contains(&scancode), "missing {scancode:?}");
+    }
+    for scancode in [Scancode::Unknown, Scancode::Reserved, Scancode::Count] {
+        assert!(!unique.contains(&scancode), "unexpected {scancode:?}");
+    }
+}
+
+#[test]
+fn all_keycodes() {
+    let all: Vec<Keycode> = Keycode::all().collect();
+    assert_eq!(all.len(), 248);
+
+    let unique: HashSet<_> = all.iter().copied().collect();
+    assert_eq!(unique.len(), all.len());
+    for keycode in [Keycode::A, Keycode::Z, Keycode::Up, Keycode::Left] {
+        assert!(unique.contains(&keycode), "missing {keycode:?}");
+    }
+    assert!(!unique.contains(&Keycode::Unknown));
+    assert!(!unique.contains(&Keycode::ScancodeMask));
+}
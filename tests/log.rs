@@ -0,0 +1,42 @@
+use sdl3::log::{self, Category, Priority};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn category_priority_round_trip() {
+    log::set_log_priority(Category::Test, Priority::Verbose);
+    assert_eq!(log::get_log_priority(Category::Test), Priority::Verbose);
+
+    log::log_message(Category::Test, Priority::Info, "logged at 100% info");
+    log::log_verbose(Category::Test, "verbose message");
+    log::log_error(Category::Test, "error message");
+    log::log("plain message with %s and %d in it");
+
+    log::reset_log_priorities();
+}
+
+#[test]
+fn output_function_captures_messages() {
+    let captured = Arc::new(Mutex::new(Vec::new()));
+    let sink = captured.clone();
+    log::set_output_function(move |priority, category, message| {
+        if message.contains("captured by output function") {
+            sink.lock()
+                .unwrap()
+                .push((priority, category, message.to_owned()));
+        }
+    });
+
+    log::log_warn(Category::Application, "captured by output function");
+    log::reset_output_function();
+    log::log_warn(Category::Application, "captured by output function, not");
+
+    let captured = captured.lock().unwrap();
+    assert_eq!(
+        *captured,
+        [(
+            Priority::Warn,
+            Category::Application,
+            "captured by output function".to_owned()
+        )]
+    );
+}
@@ -0,0 +1,124 @@
+use sdl3::properties::{Getter, Properties, PropertyType, Setter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+fn new_properties() -> Option<Properties> {
+    match Properties::new() {
+        Ok(props) => Some(props),
+        Err(err) => {
+            eprintln!("Skipping properties test: couldn't create properties: {err:?}");
+            None
+        }
+    }
+}
+
+#[test]
+fn typed_values_round_trip() {
+    let Some(props) = new_properties() else {
+        return;
+    };
+
+    let mut value = 7u8;
+    props.set("bool", true).unwrap();
+    props.set("float", 1.5f32).unwrap();
+    props.set("number", -42i64).unwrap();
+    props.set("string", "hello").unwrap();
+    props.set("pointer", &mut value as *mut u8).unwrap();
+
+    assert!(props.get("bool", false).unwrap());
+    assert_eq!(props.get("float", 0.0f32).unwrap(), 1.5);
+    assert_eq!(props.get("number", 0i64).unwrap(), -42);
+    assert_eq!(props.get_string("string", "").unwrap(), "hello");
+    let pointer: *mut u8 = props.get("pointer", std::ptr::null_mut()).unwrap();
+    assert_eq!(pointer, &mut value as *mut u8);
+
+    assert_eq!(props.get_type("bool").unwrap(), PropertyType::BOOLEAN);
+    assert_eq!(props.get_type("float").unwrap(), PropertyType::FLOAT);
+    assert_eq!(props.get_type("number").unwrap(), PropertyType::NUMBER);
+    assert_eq!(props.get_type("string").unwrap(), PropertyType::STRING);
+    assert_eq!(props.get_type("pointer").unwrap(), PropertyType::POINTER);
+}
+
+#[test]
+fn names_lists_every_property() {
+    let Some(mut props) = new_properties() else {
+        return;
+    };
+
+    props.set("a", 1i64).unwrap();
+    props.set("b", true).unwrap();
+    props.set("c", "three").unwrap();
+
+    let mut names = props.names().unwrap();
+    names.sort();
+    assert_eq!(names, ["a", "b", "c"]);
+
+    props.clear("b").unwrap();
+    assert!(!props.contains("b").unwrap());
+    let mut names = props.names().unwrap();
+    names.sort();
+    assert_eq!(names, ["a", "c"]);
+}
+
+struct DropFlag(Arc<AtomicBool>);
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn boxed_value_is_dropped_with_properties() {
+    let Some(props) = new_properties() else {
+        return;
+    };
+
+    let dropped = Arc::new(AtomicBool::new(false));
+    props
+        .set("boxed", Box::new(DropFlag(dropped.clone())))
+        .unwrap();
+    assert!(!dropped.load(Ordering::SeqCst));
+
+    drop(props);
+    assert!(dropped.load(Ordering::SeqCst));
+}
+
+#[test]
+fn cleanup_runs_when_property_is_overwritten() {
+    let Some(props) = new_properties() else {
+        return;
+    };
+
+    let cleaned = Arc::new(AtomicBool::new(false));
+    let flag = cleaned.clone();
+    let mut value = 1u32;
+    props
+        .set_with_cleanup(
+            "pointer",
+            &mut value as *mut u32,
+            Box::new(move |_| flag.store(true, Ordering::SeqCst)),
+        )
+        .unwrap();
+    assert!(!cleaned.load(Ordering::SeqCst));
+
+    props.set("pointer", 2i64).unwrap();
+    assert!(cleaned.load(Ordering::SeqCst));
+}
+
+#[test]
+fn global_properties_are_shared() {
+    let global = match Properties::global() {
+        Ok(global) => global,
+        Err(err) => {
+            eprintln!("Skipping properties test: no global properties: {err:?}");
+            return;
+        }
+    };
+
+    global.set("rust-sdl3-test-global", 5i64).unwrap();
+    let again = Properties::global().unwrap();
+    assert_eq!(again.get("rust-sdl3-test-global", 0i64).unwrap(), 5);
+    drop(global);
+    assert_eq!(again.get("rust-sdl3-test-global", 0i64).unwrap(), 5);
+}
@@ -133,6 +133,28 @@ mod raw_window_handle_test {
         }
     }
 
+    #[test]
+    fn window_handle_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<sdl3::raw_window_handle::WindowHandle<'static>>();
+    }
+
+    #[test]
+    fn window_handle_matches_window() {
+        use sdl3::raw_window_handle::WindowHandle;
+
+        let window = new_hidden_window();
+        let handle = WindowHandle::new(&window);
+        assert_eq!(
+            handle.window_handle().unwrap().as_raw(),
+            window.window_handle().unwrap().as_raw()
+        );
+        assert_eq!(
+            handle.display_handle().unwrap().as_raw(),
+            window.display_handle().unwrap().as_raw()
+        );
+    }
+
     pub fn new_hidden_window() -> Window {
         let context = sdl3::init().unwrap();
         let video_subsystem = context.video().unwrap();
@@ -1,4 +1,31 @@
-use sdl3::{rect::Rect, render::create_renderer, render::ClippingRect};
+use sdl3::{
+    pixels::{Color, PixelFormat},
+    rect::Rect,
+    render::create_renderer,
+    render::{
+        BlendFactor, BlendMode, BlendOperation, Canvas, ClippingRect, FPoint, FRect, ScaleMode,
+        SpriteSheet, TextureAccess, VSync,
+    },
+    surface::Surface,
+};
+
+/// A software canvas drawing into a surface, which needs no video device.
+fn software_canvas() -> Option<Canvas<Surface<'static>>> {
+    let surface = match Surface::new(64, 64, PixelFormat::RGBA8888) {
+        Ok(surface) => surface,
+        Err(err) => {
+            eprintln!("Skipping renderer test: couldn't create surface: {err}");
+            return None;
+        }
+    };
+    match surface.into_canvas() {
+        Ok(canvas) => Some(canvas),
+        Err(err) => {
+            eprintln!("Skipping renderer test: couldn't create software renderer: {err}");
+            None
+        }
+    }
+}
 
 #[test]
 fn clipping_rect_intersection() {
@@ -111,3 +138,323 @@ fn creating_a_named_renderer() {
     // the software renderer should always be available
     create_renderer(window, Some(c"software")).unwrap();
 }
+
+#[test]
+fn frect_intersection_and_union() {
+    let rect = FRect::new(0.5, 0.5, 10.0, 10.0);
+    assert_eq!(
+        rect.intersection(FRect::new(8.0, 9.5, 5.0, 5.0)),
+        Some(FRect::new(8.0, 9.5, 2.5, 1.0))
+    );
+    assert!(rect.has_intersection(FRect::new(10.0, 10.0, 1.0, 1.0)));
+    assert_eq!(rect.intersection(FRect::new(20.0, 0.0, 1.0, 1.0)), None);
+
+    assert_eq!(
+        rect.union(FRect::new(-1.5, 2.0, 1.0, 1.0)),
+        FRect::new(-1.5, 0.5, 12.0, 10.0)
+    );
+}
+
+#[test]
+fn frect_edge_adjacent_intersection() {
+    // Float rects that only touch along an edge count as intersecting, with
+    // an empty overlap, unlike integer rects.
+    let rect = FRect::new(0.0, 0.0, 5.0, 5.0);
+    let right = FRect::new(5.0, 0.0, 5.0, 5.0);
+    assert!(rect.has_intersection(right));
+    assert_eq!(
+        rect.intersection(right),
+        Some(FRect::new(5.0, 0.0, 0.0, 5.0))
+    );
+
+    let below = FRect::new(0.0, 5.0, 5.0, 5.0);
+    assert!(rect.has_intersection(below));
+    assert_eq!(
+        rect.intersection(below),
+        Some(FRect::new(0.0, 5.0, 5.0, 0.0))
+    );
+
+    // Any gap at all separates them.
+    assert!(!rect.has_intersection(FRect::new(5.25, 0.0, 5.0, 5.0)));
+
+    assert!(!Rect::new(0, 0, 5, 5).has_intersection(Rect::new(5, 0, 5, 5)));
+}
+
+#[test]
+fn frect_rect_conversion() {
+    let rect = Rect::new(-3, 4, 10, 20);
+    let frect = FRect::from(rect);
+    assert_eq!(frect, FRect::new(-3.0, 4.0, 10.0, 20.0));
+    assert_eq!(frect.to_rect(), rect);
+
+    // partially covered pixels are included
+    assert_eq!(
+        FRect::new(0.5, 1.25, 2.0, 2.5).to_rect(),
+        Rect::new(0, 1, 3, 3)
+    );
+}
+
+#[test]
+fn frect_enclose_points() {
+    let points = [
+        FPoint::new(1.5, -2.0),
+        FPoint::new(-4.0, 7.25),
+        FPoint::new(10.0, 1.0),
+    ];
+    assert_eq!(
+        FRect::from_enclose_points(&points, None),
+        Some(FRect::new(-4.0, -2.0, 14.0, 9.25))
+    );
+    assert_eq!(FRect::from_enclose_points(&[], None), None);
+}
+
+#[test]
+fn canvas_blend_mode() {
+    let Some(mut canvas) = software_canvas() else {
+        return;
+    };
+
+    canvas.set_blend_mode(BlendMode::Add).unwrap();
+    assert_eq!(canvas.blend_mode(), BlendMode::Add);
+
+    canvas.set_blend_mode(BlendMode::None).unwrap();
+    assert_eq!(canvas.blend_mode(), BlendMode::None);
+
+    // the software renderer only supports the predefined modes, so a custom
+    // mode is reported as an error rather than panicking
+    let custom = BlendMode::custom(
+        BlendFactor::SrcAlpha,
+        BlendFactor::OneMinusSrcAlpha,
+        BlendOperation::Add,
+        BlendFactor::One,
+        BlendFactor::Zero,
+        BlendOperation::Add,
+    );
+    assert!(canvas.set_blend_mode(custom).is_err());
+    assert_eq!(canvas.blend_mode(), BlendMode::None);
+}
+
+#[test]
+fn custom_blend_mode() {
+    // the classic alpha blend expressed as a custom mode is not one of the
+    // predefined modes, since those also describe the alpha channel differently
+    let mode = BlendMode::custom(
+        BlendFactor::SrcAlpha,
+        BlendFactor::OneMinusSrcAlpha,
+        BlendOperation::Add,
+        BlendFactor::One,
+        BlendFactor::Zero,
+        BlendOperation::Add,
+    );
+    assert!(matches!(mode, BlendMode::Custom(_)));
+
+    // composing the same mode twice gives the same value
+    let again = BlendMode::custom(
+        BlendFactor::SrcAlpha,
+        BlendFactor::OneMinusSrcAlpha,
+        BlendOperation::Add,
+        BlendFactor::One,
+        BlendFactor::Zero,
+        BlendOperation::Add,
+    );
+    assert_eq!(mode, again);
+}
+
+#[test]
+fn texture_modulation() {
+    let Some(canvas) = software_canvas() else {
+        return;
+    };
+    let texture_creator = canvas.texture_creator();
+    let mut texture = texture_creator
+        .create_texture(PixelFormat::RGBA8888, TextureAccess::Static, 8, 8)
+        .unwrap();
+
+    texture.set_color_mod(255, 128, 0);
+    assert_eq!(texture.color_mod(), (255, 128, 0));
+
+    texture.set_alpha_mod(64);
+    assert_eq!(texture.alpha_mod(), 64);
+
+    texture.set_color_mod_float(0.5, 0.25, 1.0).unwrap();
+    assert_eq!(texture.color_mod_float(), (0.5, 0.25, 1.0));
+
+    texture.set_alpha_mod_float(0.75).unwrap();
+    assert_eq!(texture.alpha_mod_float(), 0.75);
+
+    texture.set_blend_mode(BlendMode::Mod).unwrap();
+    assert_eq!(texture.blend_mode(), BlendMode::Mod);
+}
+
+#[test]
+fn texture_scale_mode() {
+    let Some(canvas) = software_canvas() else {
+        return;
+    };
+    let texture_creator = canvas.texture_creator();
+    let mut texture = texture_creator
+        .create_texture(PixelFormat::RGBA8888, TextureAccess::Static, 8, 8)
+        .unwrap();
+
+    texture.set_scale_mode(ScaleMode::Nearest).unwrap();
+    assert_eq!(texture.scale_mode(), ScaleMode::Nearest);
+
+    texture.set_scale_mode(ScaleMode::Linear).unwrap();
+    assert_eq!(texture.scale_mode(), ScaleMode::Linear);
+}
+
+#[test]
+fn batched_drawing() {
+    let Some(mut canvas) = software_canvas() else {
+        return;
+    };
+
+    let points: Vec<FPoint> = (0..10_000)
+        .map(|i| FPoint::new((i % 64) as f32, (i / 64 % 64) as f32))
+        .collect();
+    canvas.draw_points(points.as_slice()).unwrap();
+    canvas.draw_lines(points.as_slice()).unwrap();
+
+    let rects: Vec<FRect> = (0..1_000)
+        .map(|i| FRect::new((i % 60) as f32, (i / 60 % 60) as f32, 4.0, 4.0))
+        .collect();
+    canvas.draw_rects(&rects).unwrap();
+    canvas.fill_rects(&rects).unwrap();
+}
+
+/// Reads back the pixel at `(x, y)` of the canvas as an RGBA color.
+fn read_pixel(canvas: &Canvas<Surface<'static>>, x: usize, y: usize) -> Color {
+    let surface = canvas
+        .read_pixels(None)
+        .unwrap()
+        .convert_format(PixelFormat::RGBA32)
+        .unwrap();
+    let pitch = surface.pitch() as usize;
+    surface.with_lock(|pixels| {
+        let offset = y * pitch + x * 4;
+        Color::RGBA(
+            pixels[offset],
+            pixels[offset + 1],
+            pixels[offset + 2],
+            pixels[offset + 3],
+        )
+    })
+}
+
+#[test]
+fn canvas_viewport() {
+    let Some(mut canvas) = software_canvas() else {
+        return;
+    };
+    canvas.set_draw_color(Color::RGB(0, 0, 0));
+    canvas.clear();
+
+    let viewport = Rect::new(16, 16, 16, 16);
+    canvas.set_viewport(viewport);
+    assert_eq!(canvas.viewport(), viewport);
+
+    // filling with no rect fills the whole viewport, and nothing else
+    canvas.set_draw_color(Color::RGB(255, 0, 0));
+    canvas.fill_rect(None).unwrap();
+
+    canvas.set_viewport(None);
+    assert_eq!(canvas.viewport(), Rect::new(0, 0, 64, 64));
+
+    assert_eq!(read_pixel(&canvas, 20, 20), Color::RGB(255, 0, 0));
+    assert_eq!(read_pixel(&canvas, 31, 31), Color::RGB(255, 0, 0));
+    assert_eq!(read_pixel(&canvas, 0, 0), Color::RGB(0, 0, 0));
+    assert_eq!(read_pixel(&canvas, 15, 20), Color::RGB(0, 0, 0));
+    assert_eq!(read_pixel(&canvas, 32, 32), Color::RGB(0, 0, 0));
+}
+
+#[test]
+fn sprite_sheet_grid_src_rect() {
+    let Some(mut canvas) = software_canvas() else {
+        return;
+    };
+    let texture_creator = canvas.texture_creator();
+    let texture = texture_creator
+        .create_texture(PixelFormat::RGBA8888, TextureAccess::Static, 128, 128)
+        .unwrap();
+
+    let sheet = SpriteSheet::grid(texture, 32, 32, 4, 16);
+    assert_eq!(sheet.len(), 16);
+    assert_eq!(sheet.src_rect(0), Rect::new(0, 0, 32, 32));
+    assert_eq!(sheet.src_rect(3), Rect::new(96, 0, 32, 32));
+    assert_eq!(sheet.src_rect(4), Rect::new(0, 32, 32, 32));
+    assert_eq!(sheet.src_rect(6), Rect::new(64, 32, 32, 32));
+    assert_eq!(sheet.src_rect(15), Rect::new(96, 96, 32, 32));
+
+    sheet
+        .draw(&mut canvas, 5, FRect::new(0.0, 0.0, 16.0, 16.0))
+        .unwrap();
+}
+
+#[test]
+fn sprite_sheet_frame_list() {
+    let Some(canvas) = software_canvas() else {
+        return;
+    };
+    let texture_creator = canvas.texture_creator();
+    let texture = texture_creator
+        .create_texture(PixelFormat::RGBA8888, TextureAccess::Static, 64, 64)
+        .unwrap();
+
+    let frames = vec![Rect::new(0, 0, 10, 20), Rect::new(10, 0, 30, 20)];
+    let sheet = SpriteSheet::from_frames(texture, frames);
+    assert_eq!(sheet.len(), 2);
+    assert_eq!(sheet.src_rect(1), Rect::new(10, 0, 30, 20));
+}
+
+#[test]
+fn software_canvas_draws_into_surface() {
+    let Some(mut canvas) = software_canvas() else {
+        return;
+    };
+    canvas.set_draw_color(Color::RGB(0, 0, 0));
+    canvas.clear();
+    canvas.set_draw_color(Color::RGB(0, 128, 255));
+    canvas.fill_rect(FRect::new(8.0, 8.0, 16.0, 16.0)).unwrap();
+    canvas.present();
+
+    let surface = canvas
+        .into_surface()
+        .convert_format(PixelFormat::RGBA32)
+        .unwrap();
+    let pitch = surface.pitch() as usize;
+    surface.with_lock(|pixels| {
+        let inside = 10 * pitch + 10 * 4;
+        assert_eq!(&pixels[inside..inside + 3], &[0, 128, 255]);
+        let outside = 30 * pitch + 30 * 4;
+        assert_eq!(&pixels[outside..outside + 3], &[0, 0, 0]);
+    });
+}
+
+#[test]
+fn vsync_round_trip() {
+    assert_eq!(VSync::from(i32::from(VSync::Adaptive)), VSync::Adaptive);
+    assert_eq!(VSync::from(2), VSync::EveryNRefresh(2));
+    assert_eq!(i32::from(VSync::EveryNRefresh(3)), 3);
+
+    // Counts that SDL stores the same way as the named modes are the same mode.
+    assert_eq!(VSync::EveryNRefresh(1), VSync::Enabled);
+    assert_eq!(VSync::EveryNRefresh(0), VSync::Disabled);
+    assert_eq!(VSync::EveryNRefresh(-1), VSync::Adaptive);
+    assert!(matches!(
+        VSync::from(i32::from(VSync::EveryNRefresh(1))),
+        VSync::Enabled
+    ));
+    assert_ne!(VSync::EveryNRefresh(2), VSync::Enabled);
+
+    let Some(mut canvas) = software_canvas() else {
+        return;
+    };
+    // A windowless software renderer can't wait for a display, so SDL rejects
+    // anything but disabling vsync there; either outcome must be reported.
+    match canvas.set_vsync(VSync::Enabled) {
+        Ok(()) => assert_eq!(canvas.vsync().unwrap(), VSync::Enabled),
+        Err(err) => eprintln!("Renderer doesn't support vsync: {err}"),
+    }
+    canvas.set_vsync(VSync::Disabled).unwrap();
+    assert_eq!(canvas.vsync().unwrap(), VSync::Disabled);
+}
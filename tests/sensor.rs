@@ -0,0 +1,35 @@
+#[cfg(feature = "hidapi")]
+mod sensor_test {
+    #[test]
+    fn sensors_enumerate_and_open() {
+        let sdl_context = match sdl3::init() {
+            Ok(sdl) => sdl,
+            Err(err) => {
+                eprintln!("Skipping sensor test: failed to init SDL: {err}");
+                return;
+            }
+        };
+        let sensor_subsystem = match sdl_context.sensor() {
+            Ok(sensor) => sensor,
+            Err(err) => {
+                eprintln!("Skipping sensor test: failed to init sensor subsystem: {err}");
+                return;
+            }
+        };
+
+        // CI machines usually have no sensors at all, which is fine.
+        let sensors = sensor_subsystem.sensors().expect("sensors failed");
+        for info in &sensors {
+            let sensor = sensor_subsystem
+                .open(info.id)
+                .expect("failed to open enumerated sensor");
+            assert_eq!(sensor.sensor_type(), info.sensor_type);
+            assert_eq!(sensor.name(), info.name);
+
+            let mut data = [0.0f32; 3];
+            sensor
+                .get_data_into(&mut data)
+                .expect("get_data_into failed");
+        }
+    }
+}
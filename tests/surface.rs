@@ -0,0 +1,146 @@
+use sdl3::pixels::{Color, PixelFormat};
+use sdl3::rect::Rect;
+use sdl3::surface::{FlipMode, Surface};
+
+#[test]
+fn lock_write_gradient_row() {
+    let mut surface = Surface::new(16, 4, PixelFormat::RGBA32).unwrap();
+
+    surface
+        .try_with_lock_mut(|pixels, pitch| {
+            assert!(pitch >= 16 * 4);
+            let row = &mut pixels[pitch..pitch + 16 * 4];
+            for (x, pixel) in row.chunks_exact_mut(4).enumerate() {
+                pixel.copy_from_slice(&[(x * 16) as u8, 0, 0, 255]);
+            }
+        })
+        .unwrap();
+
+    let pitch = surface.pitch() as usize;
+    surface.with_lock(|pixels| {
+        for x in 0..16 {
+            let offset = pitch + x * 4;
+            assert_eq!(&pixels[offset..offset + 4], &[(x * 16) as u8, 0, 0, 255]);
+        }
+    });
+}
+
+/// Reads SDL's own locked flag, since SDL surface locks nest and locking again
+/// succeeds whether or not the previous lock was released.
+fn is_locked(raw: *mut sdl3::sys::surface::SDL_Surface) -> bool {
+    unsafe { (*raw).flags.0 & sdl3::sys::surface::SDL_SURFACE_LOCKED.0 != 0 }
+}
+
+#[test]
+fn lock_is_released_after_panic() {
+    let mut surface = Surface::new(4, 4, PixelFormat::RGBA32).unwrap();
+    let raw = surface.raw();
+    let mut was_locked = false;
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        surface
+            .try_with_lock_mut(|_, _| {
+                was_locked = is_locked(raw);
+                panic!("panicking while locked")
+            })
+            .unwrap();
+    }));
+    assert!(result.is_err());
+    assert!(was_locked);
+
+    // the guard unlocked the surface while unwinding
+    assert!(!is_locked(raw));
+}
+
+#[test]
+fn from_data_wraps_buffer() {
+    // 2x2 RGBA image, one distinct color per pixel
+    #[rustfmt::skip]
+    let mut pixels = vec![
+        255, 0, 0, 255,   0, 255, 0, 255,
+        0, 0, 255, 255,   255, 255, 255, 128,
+    ];
+
+    {
+        let surface = Surface::from_data(&mut pixels, 2, 2, 8, PixelFormat::RGBA32).unwrap();
+        assert_eq!(surface.size(), (2, 2));
+        surface.with_lock(|data| assert_eq!(&data[12..16], &[255, 255, 255, 128]));
+
+        // converting reads the borrowed pixels rather than a copy
+        let converted = surface.convert_format(PixelFormat::ARGB8888).unwrap();
+        converted.with_lock(|data| {
+            let argb = u32::from_ne_bytes(data[4..8].try_into().unwrap());
+            assert_eq!(argb, 0xff00ff00);
+        });
+    }
+
+    // writes through the surface land in the caller's buffer
+    {
+        let mut surface = Surface::from_data(&mut pixels, 2, 2, 8, PixelFormat::RGBA32).unwrap();
+        surface.with_lock_mut(|data| data[0] = 7);
+    }
+    assert_eq!(pixels[0], 7);
+}
+
+#[test]
+fn from_data_rejects_short_buffer() {
+    let mut pixels = vec![0u8; 15];
+    assert!(Surface::from_data(&mut pixels, 2, 2, 8, PixelFormat::RGBA32).is_err());
+}
+
+/// Reads the RGBA bytes of the pixel at `(x, y)` of an `RGBA32` surface.
+fn rgba_at(surface: &Surface, x: usize, y: usize) -> [u8; 4] {
+    let pitch = surface.pitch() as usize;
+    surface.with_lock(|pixels| {
+        let offset = y * pitch + x * 4;
+        pixels[offset..offset + 4].try_into().unwrap()
+    })
+}
+
+#[test]
+fn color_key_skips_keyed_pixels() {
+    let magenta = Color::RGB(255, 0, 255);
+
+    let mut sprite = Surface::new(4, 4, PixelFormat::RGBA32).unwrap();
+    sprite.fill_rect(None, magenta).unwrap();
+    sprite
+        .fill_rect(Rect::new(0, 0, 2, 2), Color::RGB(255, 255, 255))
+        .unwrap();
+
+    assert!(!sprite.has_color_key());
+    sprite.set_color_key(true, magenta).unwrap();
+    assert!(sprite.has_color_key());
+    assert_eq!(sprite.color_key().unwrap(), magenta);
+
+    let mut target = Surface::new(4, 4, PixelFormat::RGBA32).unwrap();
+    target.fill_rect(None, Color::RGB(0, 0, 0)).unwrap();
+    sprite.blit(None, &mut target, None).unwrap();
+
+    assert_eq!(rgba_at(&target, 0, 0), [255, 255, 255, 255]);
+    assert_eq!(rgba_at(&target, 3, 3), [0, 0, 0, 255]);
+
+    sprite.set_color_key(false, magenta).unwrap();
+    assert!(!sprite.has_color_key());
+    assert!(sprite.color_key().is_err());
+}
+
+#[test]
+fn flip_swaps_columns_and_rows() {
+    let red = Color::RGB(255, 0, 0);
+    let blue = Color::RGB(0, 0, 255);
+
+    let mut surface = Surface::new(4, 2, PixelFormat::RGBA32).unwrap();
+    surface.fill_rect(None, blue).unwrap();
+    surface.fill_rect(Rect::new(0, 0, 1, 1), red).unwrap();
+
+    surface.flip(FlipMode::Horizontal).unwrap();
+    assert_eq!(rgba_at(&surface, 0, 0), [0, 0, 255, 255]);
+    assert_eq!(rgba_at(&surface, 3, 0), [255, 0, 0, 255]);
+
+    surface.flip(FlipMode::Vertical).unwrap();
+    assert_eq!(rgba_at(&surface, 3, 0), [0, 0, 255, 255]);
+    assert_eq!(rgba_at(&surface, 3, 1), [255, 0, 0, 255]);
+
+    surface.flip(FlipMode::Both).unwrap();
+    assert_eq!(rgba_at(&surface, 0, 0), [255, 0, 0, 255]);
+}
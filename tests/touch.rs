@@ -0,0 +1,29 @@
+use sdl3::touch::{TouchDevice, TouchDeviceType};
+
+#[test]
+fn touch_devices_enumerate_without_panicking() {
+    let sdl_context = match sdl3::init() {
+        Ok(sdl) => sdl,
+        Err(err) => {
+            eprintln!("Skipping touch test: failed to init SDL: {err}");
+            return;
+        }
+    };
+    // Touch devices are tracked by the video subsystem.
+    let _video = match sdl_context.video() {
+        Ok(video) => video,
+        Err(err) => {
+            eprintln!("Skipping touch test: failed to init video subsystem: {err}");
+            return;
+        }
+    };
+
+    // CI machines usually have no touch devices, which is fine.
+    for device in TouchDevice::all() {
+        assert_ne!(device.device_type(), TouchDeviceType::Invalid);
+        let _ = device.name();
+        for finger in device.fingers() {
+            assert!((0.0..=1.0).contains(&finger.pressure));
+        }
+    }
+}
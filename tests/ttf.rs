@@ -0,0 +1,134 @@
+#[cfg(feature = "ttf")]
+mod ttf_test {
+    use sdl3::pixels::Color;
+    use sdl3::ttf::{self, Font, FontStyle, Sdl3TtfContext};
+
+    /// Fonts commonly installed on CI machines; set `SDL3_TEST_FONT` to use
+    /// another one.
+    const FONT_PATHS: &[&str] = &[
+        "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf",
+        "/usr/share/fonts/TTF/DejaVuSans.ttf",
+        "/Library/Fonts/Arial Unicode.ttf",
+        "/System/Library/Fonts/Supplemental/Arial.ttf",
+        "C:\\Windows\\Fonts\\arial.ttf",
+    ];
+
+    fn load_test_font(context: &Sdl3TtfContext) -> Option<Font<'static>> {
+        let path = std::env::var("SDL3_TEST_FONT").ok().or_else(|| {
+            FONT_PATHS
+                .iter()
+                .find(|path| std::path::Path::new(path).exists())
+                .map(|path| path.to_string())
+        });
+        let Some(path) = path else {
+            eprintln!("Skipping ttf test: no font found, set SDL3_TEST_FONT");
+            return None;
+        };
+        match context.load_font(&path, 16.0) {
+            Ok(font) => Some(font),
+            Err(err) => {
+                eprintln!("Skipping ttf test: couldn't load {path}: {err}");
+                None
+            }
+        }
+    }
+
+    fn init() -> Option<Sdl3TtfContext> {
+        match ttf::init() {
+            Ok(context) => Some(context),
+            Err(err) => {
+                eprintln!("Skipping ttf test: failed to init SDL_ttf: {err}");
+                None
+            }
+        }
+    }
+
+    #[test]
+    fn measure_text() {
+        let Some(context) = init() else {
+            return;
+        };
+        let Some(font) = load_test_font(&context) else {
+            return;
+        };
+
+        let text = "Hello, world";
+        let (width, height) = font.size_of(text).unwrap();
+        assert!(width > 0 && height > 0);
+
+        let (fit_width, fit_length) = font.measure(text, width).unwrap();
+        assert_eq!((fit_width, fit_length), (width, text.len()));
+
+        let (fit_width, fit_length) = font.measure(text, width / 2).unwrap();
+        assert!(fit_width <= width / 2);
+        assert!(fit_length > 0 && fit_length < text.len());
+    }
+
+    #[test]
+    fn render_wrapped_text() {
+        let Some(context) = init() else {
+            return;
+        };
+        let Some(font) = load_test_font(&context) else {
+            return;
+        };
+
+        let text = "The quick brown fox jumps over the lazy dog";
+        let line = font.render(text).blended(Color::WHITE).unwrap();
+        let wrapped = font.render_wrapped(text, Color::WHITE, 100).unwrap();
+        assert!(wrapped.width() <= 100);
+        assert!(wrapped.height() > line.height());
+
+        let lines = font
+            .render("one\ntwo")
+            .solid_wrapped(Color::WHITE, 0)
+            .unwrap();
+        assert!(lines.height() > line.height());
+    }
+
+    #[test]
+    fn style_and_outline_change_rendering() {
+        let Some(context) = init() else {
+            return;
+        };
+        let Some(mut font) = load_test_font(&context) else {
+            return;
+        };
+
+        let text = "Bold move";
+        let regular = font.render(text).blended(Color::WHITE).unwrap();
+
+        font.set_style(FontStyle::BOLD | FontStyle::UNDERLINE);
+        assert_eq!(font.get_style(), FontStyle::BOLD | FontStyle::UNDERLINE);
+        let bold = font.render(text).blended(Color::WHITE).unwrap();
+        assert_ne!(bold.width(), regular.width());
+
+        font.set_style(FontStyle::NORMAL);
+        font.set_outline_width(2).unwrap();
+        assert_eq!(font.get_outline_width(), 2);
+        let outlined = font.render(text).blended(Color::WHITE).unwrap();
+        assert!(outlined.width() > regular.width());
+
+        font.set_kerning(false);
+        assert!(!font.get_kerning());
+    }
+
+    #[test]
+    fn glyph_and_font_metrics() {
+        let Some(context) = init() else {
+            return;
+        };
+        let Some(font) = load_test_font(&context) else {
+            return;
+        };
+
+        let metrics = font.glyph_metrics('A').unwrap();
+        assert!(metrics.advance > 0);
+        assert!(metrics.maxx > metrics.minx && metrics.maxy > metrics.miny);
+
+        assert!(font.ascent() > 0);
+        assert!(font.descent() <= 0);
+        assert!(font.height() >= font.ascent());
+        assert!(font.line_skip() > 0);
+    }
+}
@@ -0,0 +1,22 @@
+use sdl3::version::{self, Version};
+
+#[test]
+fn linked_version_is_sdl3() {
+    let linked = Version::linked();
+    assert!(linked >= Version::new(3, 0, 0));
+    assert!(linked < Version::new(4, 0, 0));
+    assert_eq!(linked, version::version());
+    assert!(Version::current() >= Version::new(3, 0, 0));
+
+    // Only checks it doesn't crash, custom builds may not have a revision.
+    let _ = version::revision();
+}
+
+#[test]
+fn version_ordering() {
+    assert!(Version::new(3, 2, 0) > Version::new(3, 1, 9));
+    assert!(Version::new(3, 1, 10) > Version::new(3, 1, 9));
+    assert!(Version::new(2, 30, 0) < Version::new(3, 0, 0));
+    assert_eq!(Version::from_ll(3_002_010), Version::new(3, 2, 10));
+    assert_eq!(Version::new(3, 2, 10).to_string(), "3.2.10");
+}
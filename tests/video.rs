@@ -17,9 +17,28 @@ fn display_name_no_segfault() {
 */
 
 use sdl3::render::Canvas;
-use sdl3::video::Window;
+use sdl3::video::{Window, WindowFlags};
 use sdl3::VideoSubsystem;
 
+/// Initializes SDL and the video subsystem, or returns `None` (after logging why)
+/// if there is no usable video device.
+fn init_video() -> Option<(sdl3::Sdl, VideoSubsystem)> {
+    let sdl_context = match sdl3::init() {
+        Ok(ctx) => ctx,
+        Err(err) => {
+            eprintln!("Skipping video test: failed to init SDL: {err}");
+            return None;
+        }
+    };
+    match sdl_context.video() {
+        Ok(video) => Some((sdl_context, video)),
+        Err(err) => {
+            eprintln!("Skipping video test: no video device available: {err}");
+            None
+        }
+    }
+}
+
 fn build_canvas(video_subsystem: &VideoSubsystem) -> Result<Canvas<Window>, String> {
     let window = video_subsystem
         .window("rust-sdl3 test: Video", 800, 600)
@@ -67,5 +86,230 @@ fn window_from_id() {
             return;
         }
     };
-    window.maximize();
+    window.maximize().unwrap();
+}
+
+#[test]
+fn safe_area_covers_desktop_window() {
+    let sdl_context = match sdl3::init() {
+        Ok(ctx) => ctx,
+        Err(err) => {
+            eprintln!("Skipping video test: failed to init SDL: {err}");
+            return;
+        }
+    };
+    let video_subsystem = match sdl_context.video() {
+        Ok(video) => video,
+        Err(err) => {
+            eprintln!("Skipping video test: no video device available: {err}");
+            return;
+        }
+    };
+
+    let window = match video_subsystem
+        .window("rust-sdl3 test: safe area", 320, 240)
+        .hidden()
+        .build()
+    {
+        Ok(window) => window,
+        Err(err) => {
+            eprintln!("Skipping video test: couldn't build window: {err}");
+            return;
+        }
+    };
+
+    let safe_area = match window.safe_area() {
+        Ok(rect) => rect,
+        Err(err) => {
+            eprintln!("Skipping video test: safe area unavailable: {err}");
+            return;
+        }
+    };
+    let (w, h) = window.size();
+    assert_eq!((safe_area.x(), safe_area.y()), (0, 0));
+    assert_eq!((safe_area.width(), safe_area.height()), (w, h));
+}
+
+#[test]
+fn display_bounds_all_contains_primary() {
+    let sdl_context = match sdl3::init() {
+        Ok(ctx) => ctx,
+        Err(err) => {
+            eprintln!("Skipping video test: failed to init SDL: {err}");
+            return;
+        }
+    };
+    let video_subsystem = match sdl_context.video() {
+        Ok(video) => video,
+        Err(err) => {
+            eprintln!("Skipping video test: no video device available: {err}");
+            return;
+        }
+    };
+
+    let primary = match video_subsystem.get_primary_display() {
+        Ok(display) => display,
+        Err(err) => {
+            eprintln!("Skipping video test: no primary display: {err}");
+            return;
+        }
+    };
+    let all = video_subsystem.get_display_bounds_all().unwrap();
+    let (_, bounds) = all
+        .iter()
+        .find(|(display, _)| *display == primary)
+        .expect("primary display missing from get_display_bounds_all");
+    assert!(bounds.width() > 0 && bounds.height() > 0);
+
+    assert_eq!(
+        video_subsystem.get_display_at_point(bounds.center()),
+        Some(primary)
+    );
+}
+
+#[test]
+fn desktop_and_current_display_mode() {
+    let Some((_sdl_context, video_subsystem)) = init_video() else {
+        return;
+    };
+    let primary = match video_subsystem.get_primary_display() {
+        Ok(display) => display,
+        Err(err) => {
+            eprintln!("Skipping video test: no primary display: {err}");
+            return;
+        }
+    };
+
+    let desktop = video_subsystem.desktop_display_mode(&primary).unwrap();
+    let current = video_subsystem.current_display_mode(&primary).unwrap();
+    assert!(desktop.w > 0 && desktop.h > 0);
+    assert!(current.w > 0 && current.h > 0);
+
+    // nothing switched the display into a fullscreen mode
+    assert_eq!((current.w, current.h), (desktop.w, desktop.h));
+}
+
+/// Builds a small hidden window, or returns `None` (after logging why).
+fn build_hidden_window(video_subsystem: &VideoSubsystem) -> Option<Window> {
+    match video_subsystem
+        .window("rust-sdl3 test: Window", 320, 240)
+        .hidden()
+        .build()
+    {
+        Ok(window) => Some(window),
+        Err(err) => {
+            eprintln!("Skipping video test: couldn't create window: {err}");
+            None
+        }
+    }
+}
+
+#[test]
+fn window_mouse_grab() {
+    let Some((_sdl_context, video_subsystem)) = init_video() else {
+        return;
+    };
+    let Some(mut window) = build_hidden_window(&video_subsystem) else {
+        return;
+    };
+
+    // A hidden window never has input focus, so the grab is only requested,
+    // never active: check the requested flag rather than `mouse_grab()`.
+    assert!(!window.has_input_grabbed());
+    window.set_mouse_grab(true).unwrap();
+    assert!(window.has_input_grabbed());
+    window.set_mouse_grab(false).unwrap();
+    assert!(!window.has_input_grabbed());
+    assert!(!window.mouse_grab());
+
+    let rect = sdl3::rect::Rect::new(10, 10, 100, 50);
+    window.set_mouse_rect(rect).unwrap();
+    assert_eq!(window.mouse_rect(), Some(rect));
+    window.set_mouse_rect(None).unwrap();
+    assert_eq!(window.mouse_rect(), None);
+}
+
+#[test]
+fn window_runtime_flags() {
+    let Some((_sdl_context, video_subsystem)) = init_video() else {
+        return;
+    };
+    let Some(mut window) = build_hidden_window(&video_subsystem) else {
+        return;
+    };
+    let flags = |window: &Window| WindowFlags::from(window.window_flags());
+
+    assert!(!flags(&window).contains(WindowFlags::RESIZABLE));
+    window.set_resizable(true).unwrap();
+    assert!(flags(&window).contains(WindowFlags::RESIZABLE));
+    window.set_resizable(false).unwrap();
+    assert!(!flags(&window).contains(WindowFlags::RESIZABLE));
+
+    window.set_bordered(false).unwrap();
+    assert!(flags(&window).contains(WindowFlags::BORDERLESS));
+    window.set_bordered(true).unwrap();
+    assert!(!flags(&window).contains(WindowFlags::BORDERLESS));
+}
+
+#[test]
+fn window_size_limits() {
+    let Some((_sdl_context, video_subsystem)) = init_video() else {
+        return;
+    };
+    let Some(mut window) = build_hidden_window(&video_subsystem) else {
+        return;
+    };
+
+    window.set_minimum_size(320, 240).unwrap();
+    assert_eq!(window.minimum_size(), (320, 240));
+
+    window.set_maximum_size(1280, 720).unwrap();
+    assert_eq!(window.maximum_size(), (1280, 720));
+
+    // SDL refuses a minimum larger than the maximum
+    assert!(window.set_minimum_size(1920, 1080).is_err());
+
+    // Zero sizes are rejected before reaching SDL, leaving the limits alone
+    assert!(window.set_minimum_size(0, 240).is_err());
+    assert!(window.set_maximum_size(1280, 0).is_err());
+    assert_eq!(window.minimum_size(), (320, 240));
+    assert_eq!(window.maximum_size(), (1280, 720));
+}
+
+#[test]
+fn window_show_and_hide() {
+    let Some((_sdl_context, video_subsystem)) = init_video() else {
+        return;
+    };
+    let Some(mut window) = build_hidden_window(&video_subsystem) else {
+        return;
+    };
+    let hidden =
+        |window: &Window| WindowFlags::from(window.window_flags()).contains(WindowFlags::HIDDEN);
+
+    assert!(hidden(&window));
+    window.show().unwrap();
+    window.sync().ok();
+    assert!(!hidden(&window));
+
+    window.hide().unwrap();
+    window.sync().ok();
+    assert!(hidden(&window));
+}
+
+#[test]
+fn window_pixel_size_and_density() {
+    let Some((_sdl_context, video_subsystem)) = init_video() else {
+        return;
+    };
+    let Some(window) = build_hidden_window(&video_subsystem) else {
+        return;
+    };
+
+    let (w, h) = window.size();
+    let (pw, ph) = window.size_in_pixels();
+    assert!(pw >= w && ph >= h);
+
+    assert!(window.pixel_density() > 0.0);
+    assert!(window.display_scale() > 0.0);
 }